@@ -0,0 +1,50 @@
+//! Rough throughput measurements for the hot paths of the crate
+//!
+//! Run with `cargo bench`, each benchmark prints the average time per run
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use spatial_slice::*;
+
+/// Runs the function repeatedly and prints the average time it took
+fn bench<F: FnMut()>(name: &str, runs: u32, mut func: F) {
+    func();
+
+    let start = Instant::now();
+
+    for _ in 0 .. runs {
+        func();
+    }
+
+    println!("{:<32} {:>12.3?}", name, start.elapsed() / runs);
+}
+
+fn main() {
+    let space = Space::new_mapped(|x, y| (x ^ y) as u32, 2_048, 2_048);
+
+    bench("subspace iter (full)", 20, || {
+        let sum = space.as_subspace().iter().fold(0u32, |acc, v| acc.wrapping_add(*v));
+
+        black_box(sum);
+    });
+
+    bench("subspace iter (right half)", 20, || {
+        let subspace = space.as_subspace();
+        let HorizontalSplit { right, .. } = subspace.split_horizontal(PositioningType::Absolute, 1_024);
+
+        let sum = right.iter().fold(0u32, |acc, v| acc.wrapping_add(*v));
+
+        black_box(sum);
+    });
+
+    let floats = Space::new_mapped(|x, y| (x + y) as f32 * 0.5, 2_048, 2_048);
+
+    bench("f32 sum (chunked)", 20, || {
+        black_box(floats.sum());
+    });
+
+    bench("f32 sum (naive fold)", 20, || {
+        black_box(floats.iter().fold(0.0f32, |acc, v| acc + *v));
+    });
+}
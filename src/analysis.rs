@@ -0,0 +1,218 @@
+use std::ops::Add;
+
+use crate::SubSpace;
+use crate::primitives::*;
+
+/// A list of relative positions within a SubSpace
+type Positions = Vec<(usize, usize)>;
+
+impl<'a, T> SubSpace<'a, T> {
+    /// Counts, for every row, the number of horizontally adjacent pairs of cells
+    /// where the boundary function returns true when given the left and right cell
+    #[inline]
+    pub fn row_transitions<F: Fn(&T, &T) -> bool>(&self, boundary: F) -> Vec<usize> {
+        (0 .. self.height())
+            .map(|y| {
+                (1 .. self.width())
+                    .filter(|x| {
+                        let left = self.get(PositioningType::Relative, x - 1, y);
+                        let right = self.get(PositioningType::Relative, *x, y);
+
+                        matches!((left, right), (Some(a), Some(b)) if boundary(a, b))
+                    })
+                    .count()
+            })
+            .collect()
+    }
+
+    /// Counts, for every column, the number of vertically adjacent pairs of cells
+    /// where the boundary function returns true when given the upper and lower cell
+    #[inline]
+    pub fn column_transitions<F: Fn(&T, &T) -> bool>(&self, boundary: F) -> Vec<usize> {
+        (0 .. self.width())
+            .map(|x| {
+                (1 .. self.height())
+                    .filter(|y| {
+                        let above = self.get(PositioningType::Relative, x, y - 1);
+                        let below = self.get(PositioningType::Relative, x, *y);
+
+                        matches!((above, below), (Some(a), Some(b)) if boundary(a, b))
+                    })
+                    .count()
+            })
+            .collect()
+    }
+
+    /// Finds the relative position and value of the largest cell,
+    /// on ties the first cell read lexicographically wins
+    /// If the SubSpace has no cells None is returned
+    #[inline]
+    pub fn argmax(&self) -> Option<((usize, usize), &'a T)>
+        where T: PartialOrd {
+
+        self.arg_best(|candidate, best| candidate > best)
+    }
+
+    /// Finds the relative position and value of the smallest cell,
+    /// on ties the first cell read lexicographically wins
+    /// If the SubSpace has no cells None is returned
+    #[inline]
+    pub fn argmin(&self) -> Option<((usize, usize), &'a T)>
+        where T: PartialOrd {
+
+        self.arg_best(|candidate, best| candidate < best)
+    }
+
+    /// Finds the smallest and largest values in a single pass,
+    /// on ties the first cell read lexicographically wins for both
+    /// If the SubSpace has no cells None is returned
+    #[inline]
+    pub fn min_max(&self) -> Option<(&'a T, &'a T)>
+        where T: PartialOrd {
+
+        let mut iter = self.iter();
+        let first = iter.next()?;
+
+        Some(iter.fold((first, first), |(min, max), value| {
+            if value < min {
+                (value, max)
+            } else if value > max {
+                (min, value)
+            } else {
+                (min, max)
+            }
+        }))
+    }
+
+    /// Splits the relative positions of the cells into those that satisfy the predicate
+    /// and those that do not, each in lexicographic order
+    #[inline]
+    pub fn partition_coords<F: Fn(&T) -> bool>(&self, pred: F) -> (Positions, Positions) {
+        let width = self.width();
+
+        let (matching, rest): (Vec<_>, Vec<_>) = self.iter()
+            .enumerate()
+            .partition(|(_, value)| pred(value));
+
+        let coords = |cells: Vec<(usize, &T)>| cells.into_iter().map(|(index, _)| (index % width, index / width)).collect();
+
+        (coords(matching), coords(rest))
+    }
+
+    /// Collects the cells of the main diagonal, where the relative x and y are equal,
+    /// from the top left corner for min(width, height) cells
+    #[inline]
+    pub fn diagonal(&self) -> Vec<&'a T> {
+        (0 .. self.width().min(self.height()))
+            .filter_map(|i| self.get(PositioningType::Relative, i, i))
+            .collect()
+    }
+
+    /// Collects the cells of the anti diagonal, from the top right corner
+    /// down and to the left for min(width, height) cells
+    #[inline]
+    pub fn anti_diagonal(&self) -> Vec<&'a T> {
+        let width = self.width();
+
+        (0 .. width.min(self.height()))
+            .filter_map(|i| self.get(PositioningType::Relative, width - 1 - i, i))
+            .collect()
+    }
+
+    /// Sums the cells of the main diagonal, see `diagonal`
+    /// A SubSpace without cells has a trace of T::default()
+    #[inline]
+    pub fn trace(&self) -> T
+        where T: Add<Output = T> + Default + Copy {
+
+        self.diagonal().into_iter().fold(T::default(), |sum, value| sum + *value)
+    }
+
+    /// Finds the relative position and value of the first cell that no later cell replaces,
+    /// a later cell replaces the current best when the function returns true
+    #[inline]
+    fn arg_best<F: Fn(&T, &T) -> bool>(&self, replaces: F) -> Option<((usize, usize), &'a T)> {
+        let width = self.width();
+
+        let (index, value) = self.iter()
+            .enumerate()
+            .reduce(|best, candidate| if replaces(candidate.1, best.1) { candidate } else { best })?;
+
+        Some(((index % width, index / width), value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Space;
+
+    #[test]
+    fn transitions_test() {
+        // Row y has stripes that are y + 1 cells wide
+        let space = Space::new_mapped(|x, y| (x / (y + 1)) % 2 == 0, 6, 3);
+        let subspace = space.as_subspace();
+
+        assert_eq!(subspace.row_transitions(|a, b| a != b), vec![5, 2, 1]);
+        assert_eq!(subspace.column_transitions(|a, b| a != b), vec![0, 1, 2, 0, 1, 2]);
+    }
+
+    #[test]
+    fn argmax_argmin_test() {
+        let space = Space::new_mapped(|x, y| (x * y) as i32 - 3, 5, 4);
+        let subspace = space.as_subspace();
+
+        assert_eq!(subspace.argmax(), Some(((4, 3), &9)));
+        assert_eq!(subspace.argmin(), Some(((0, 0), &-3)));
+
+        let flat = Space::new_flat(1u8, 3, 3);
+
+        assert_eq!(flat.as_subspace().argmax(), Some(((0, 0), &1)));
+        assert_eq!(Space::new_flat(1u8, 0, 3).as_subspace().argmin(), None);
+    }
+
+    #[test]
+    fn partition_coords_test() {
+        let board = Space::new_mapped(|x, y| (x + y) % 2 == 0, 5, 4);
+        let subspace = board.as_subspace_at(1, 0, 3, 3).unwrap();
+
+        let (white, black) = subspace.partition_coords(|cell| *cell);
+
+        assert_eq!(white.len(), 4);
+        assert_eq!(black.len(), 5);
+        assert_eq!(white[..2], [(1, 0), (0, 1)]);
+        assert!(black.iter().all(|(x, y)| (x + y) % 2 == 0));
+        assert!(white.iter().all(|position| !black.contains(position)));
+    }
+
+    #[test]
+    fn min_max_test() {
+        let space = Space::new_mapped(|x, y| ((x as f32) - 2.0) * ((y as f32) - 1.5), 5, 4);
+
+        assert_eq!(space.as_subspace().min_max(), Some((&-3.0, &3.0)));
+        assert_eq!(space.as_subspace_at(2, 0, 3, 2).unwrap().min_max(), Some((&-3.0, &0.0)));
+
+        // Ties keep the first occurrence
+        let ties = Space::new_mapped(|x, _| [1.0f32, -0.0, 0.0, 1.0][x], 4, 1);
+        let (min, max) = ties.as_subspace().min_max().unwrap();
+
+        assert!(min.is_sign_negative());
+        assert!(std::ptr::eq(max, ties.get(0, 0).unwrap()));
+        assert_eq!(Space::new_flat(0u8, 0, 2).as_subspace().min_max(), None);
+    }
+
+    #[test]
+    fn diagonal_trace_test() {
+        let space = Space::new_mapped(|x, y| (y * 4 + x) as i32, 4, 4);
+        let subspace = space.as_subspace();
+
+        assert_eq!(subspace.diagonal(), vec![&0, &5, &10, &15]);
+        assert_eq!(subspace.anti_diagonal(), vec![&3, &6, &9, &12]);
+        assert_eq!(subspace.trace(), 30);
+
+        let wide = space.as_subspace_at(1, 1, 3, 2).unwrap();
+
+        assert_eq!(wide.diagonal(), vec![&5, &10]);
+        assert_eq!(wide.anti_diagonal(), vec![&7, &10]);
+        assert_eq!(space.as_subspace_at(0, 0, 0, 4).unwrap().trace(), 0);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::Space;
+
+impl<T, const W: usize, const H: usize> From<[[T; W]; H]> for Space<T> {
+    /// Creates a space from an array of rows, so the array is W wide and H high
+    #[inline]
+    fn from(rows: [[T; W]; H]) -> Self {
+        // Arrays are iterated by reference with `.into_iter()` in this edition
+        let data = IntoIterator::into_iter(rows)
+            .flat_map(IntoIterator::into_iter)
+            .collect::<Vec<_>>();
+
+        Space {
+            data: data.into_boxed_slice(),
+            width: W,
+            height: H
+        }
+    }
+}
+
+impl<T> Space<T> {
+    /// Copies the space into an array of rows, which is the inverse of creating a space from one
+    /// If the space is not W wide and H high None is returned
+    #[inline]
+    pub fn to_2d_array<const W: usize, const H: usize>(&self) -> Option<[[T; W]; H]>
+        where T: Copy {
+
+        if self.width != W || self.height != H {
+            return None;
+        }
+
+        Some(std::array::from_fn(|y| std::array::from_fn(|x| self.data[y * W + x])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn array_round_trip_test() {
+        let space = Space::new_mapped(|x, y| (y * 3 + x) as u8, 3, 2);
+
+        let array = space.to_2d_array::<3, 2>().unwrap();
+
+        assert_eq!(array, [[0, 1, 2], [3, 4, 5]]);
+        assert_eq!(Space::from(array), space);
+
+        assert_eq!(space.to_2d_array::<2, 3>(), None);
+    }
+}
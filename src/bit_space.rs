@@ -0,0 +1,176 @@
+use crate::Space;
+
+/// The number of cells stored in each word of a BitSpace
+const WORD_BITS: usize = 64;
+
+/// A BitSpace represents a rectangular 2 dimensional array of booleans,
+/// packed so that every cell takes up a single bit of memory
+///
+/// The cells are stored lexicographically, so a row may begin partway through a word
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct BitSpace {
+    /// The linear memory that the bits are stored in,
+    /// any bits past the last cell are always zero
+    words: Box<[u64]>,
+
+    /// The width (X direction) of the BitSpace
+    width: usize,
+
+    /// The height (Y direction) of the BitSpace
+    height: usize
+}
+
+impl BitSpace {
+    /// Creates a bit space where every cell is the provided value,
+    /// with the provided dimensions
+    #[inline]
+    pub fn new_flat(value: bool, width: usize, height: usize) -> Self {
+        let size = width * height;
+        let word = if value { u64::MAX } else { 0 };
+
+        let mut words = vec![ word; size.div_ceil(WORD_BITS) ].into_boxed_slice();
+
+        let used_bits = size % WORD_BITS;
+
+        if let Some(last) = words.last_mut() {
+            if used_bits != 0 {
+                *last &= (1 << used_bits) - 1;
+            }
+        }
+
+        BitSpace {
+            words,
+            width,
+            height
+        }
+    }
+
+    /// The width (X direction) of the BitSpace
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height (Y direction) of the BitSpace
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Finds the word and the bit within it that store the cell at the given position
+    #[inline]
+    fn locate(&self, x: usize, y: usize) -> Option<(usize, u64)> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let index = y * self.width + x;
+
+        Some((index / WORD_BITS, 1 << (index % WORD_BITS)))
+    }
+
+    /// Reads the value of the cell at the specified position
+    /// If the position specified is outside the space None is returned
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<bool> {
+        let (word, mask) = self.locate(x, y)?;
+
+        Some(self.words[word] & mask != 0)
+    }
+
+    /// Sets the value for the specified position in the space
+    /// If the position specified is outside the space false is returned
+    #[inline]
+    pub fn set(&mut self, x: usize, y: usize, value: bool) -> bool {
+        if let Some((word, mask)) = self.locate(x, y) {
+            if value {
+                self.words[word] |= mask;
+            } else {
+                self.words[word] &= !mask;
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Counts the number of cells that are set to true
+    #[inline]
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Unpacks this bit space into a space with one bool per cell
+    #[inline]
+    pub fn to_space(&self) -> Space<bool> {
+        let size = self.width * self.height;
+
+        let data = (0 .. size)
+            .map(|index| self.words[index / WORD_BITS] & (1 << (index % WORD_BITS)) != 0)
+            .collect::<Vec<_>>();
+
+        Space {
+            data: data.into_boxed_slice(),
+            width: self.width,
+            height: self.height
+        }
+    }
+}
+
+impl From<&Space<bool>> for BitSpace {
+    #[inline]
+    fn from(space: &Space<bool>) -> Self {
+        let mut words = vec![ 0u64; space.data.len().div_ceil(WORD_BITS) ].into_boxed_slice();
+
+        for (index, value) in space.data.iter().enumerate() {
+            if *value {
+                words[index / WORD_BITS] |= 1 << (index % WORD_BITS);
+            }
+        }
+
+        BitSpace {
+            words,
+            width: space.width,
+            height: space.height
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scattered_bits_test() {
+        let mut bits = BitSpace::new_flat(false, 67, 13);
+
+        let points = [(0, 0), (66, 0), (0, 1), (63, 0), (64, 0), (12, 7), (66, 12)];
+
+        for (x, y) in points.iter() {
+            assert!(bits.set(*x, *y, true));
+        }
+
+        for y in 0 .. 13 {
+            for x in 0 .. 67 {
+                assert_eq!(bits.get(x, y), Some(points.contains(&(x, y))));
+            }
+        }
+
+        assert!(bits.set(63, 0, false));
+        assert_eq!(bits.get(63, 0), Some(false));
+
+        assert!(!bits.set(67, 0, true));
+        assert_eq!(bits.get(0, 13), None);
+    }
+
+    #[test]
+    fn count_ones_test() {
+        let space = Space::new_mapped(|x, y| (x * 3 + y * 5) % 7 == 0, 33, 9);
+        let bits = BitSpace::from(&space);
+
+        assert_eq!(bits.count_ones(), space.iter().filter(|v| **v).count());
+        assert_eq!(BitSpace::new_flat(true, 33, 9).count_ones(), 33 * 9);
+        assert_eq!(bits.to_space(), space);
+    }
+}
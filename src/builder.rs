@@ -0,0 +1,149 @@
+use crate::Space;
+use crate::error::*;
+
+/// Builds a space one row at a time, which suits data that arrives line by line,
+/// such as a file being parsed
+/// The length of the first row is used as the width, a builder without rows builds a 0x0 space
+#[derive(Clone, Debug, Default)]
+pub struct SpaceBuilder<T> {
+    /// The cells of the rows pushed so far, stored lexicographically
+    data: Vec<T>,
+
+    /// The width (X direction) of every row, taken from the first row
+    width: usize,
+
+    /// The number of rows pushed so far
+    height: usize
+}
+
+impl<T> SpaceBuilder<T> {
+    /// Creates a builder without any rows
+    #[inline]
+    pub fn new() -> Self {
+        SpaceBuilder {
+            data: Vec::new(),
+            width: 0,
+            height: 0
+        }
+    }
+
+    /// Creates a builder without any rows that has room for the expected number of rows
+    /// of the expected width, so pushing them does not reallocate
+    #[inline]
+    pub fn with_capacity(expected_rows: usize, expected_width: usize) -> Self {
+        SpaceBuilder {
+            data: Vec::with_capacity(expected_rows * expected_width),
+            width: 0,
+            height: 0
+        }
+    }
+
+    /// The number of rows pushed so far
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of cells the builder can hold without reallocating
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    /// Grows the capacity of the builder so that at least the provided number of additional rows
+    /// can be pushed without reallocating
+    /// Before the first row is pushed the width is unknown, so nothing is reserved
+    #[inline]
+    pub fn reserve_rows(&mut self, additional: usize) {
+        self.data.reserve(additional * self.width);
+    }
+
+    /// Appends a row below the rows pushed so far
+    /// If the row has a different length than the first row an error describing it is returned
+    /// and the builder is left unchanged
+    #[inline]
+    pub fn push_row<I: IntoIterator<Item = T>>(&mut self, row: I) -> Result<(), ShapeError> {
+        let start = self.data.len();
+
+        self.data.extend(row);
+
+        let found = self.data.len() - start;
+
+        if self.height == 0 {
+            self.width = found;
+        } else if found != self.width {
+            self.data.truncate(start);
+
+            return Err(ShapeError {
+                index: self.height,
+                expected: self.width,
+                found
+            });
+        }
+
+        self.height += 1;
+
+        Ok(())
+    }
+
+    /// Creates a space from the rows pushed so far
+    #[inline]
+    pub fn build(self) -> Space<T> {
+        Space {
+            data: self.data.into_boxed_slice(),
+            width: self.width,
+            height: self.height
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_row_test() {
+        let mut builder = SpaceBuilder::new();
+
+        assert!(builder.push_row(vec![1, 2, 3]).is_ok());
+        assert!(builder.push_row(4 ..= 6).is_ok());
+        assert_eq!(builder.push_row(vec![7, 8]), Err(ShapeError { index: 2, expected: 3, found: 2 }));
+        assert_eq!(builder.height(), 2);
+
+        let space = builder.build();
+
+        assert_eq!((space.width(), space.height()), (3, 2));
+        assert_eq!(space.get(0, 1), Some(&4));
+
+        assert_eq!(SpaceBuilder::<u8>::new().build().len(), 0);
+    }
+
+    #[test]
+    fn with_capacity_test() {
+        let mut builder = SpaceBuilder::with_capacity(500, 200);
+        let capacity = builder.capacity();
+        let mut reallocations = 0;
+
+        for y in 0 .. 500 {
+            builder.push_row((0 .. 200).map(|x| x + y)).unwrap();
+
+            if builder.capacity() != capacity {
+                reallocations += 1;
+            }
+        }
+
+        assert_eq!(reallocations, 0);
+
+        builder.reserve_rows(100);
+        let capacity = builder.capacity();
+
+        assert!(capacity >= 600 * 200);
+
+        for y in 0 .. 100 {
+            builder.push_row((0 .. 200).map(|x| x + y)).unwrap();
+        }
+
+        assert_eq!(builder.capacity(), capacity);
+        assert_eq!(builder.build().height(), 600);
+    }
+}
@@ -0,0 +1,117 @@
+use crate::Space;
+use crate::error::*;
+
+impl<const N: usize> Space<[u8; N]> {
+    /// Creates a new space holding one channel of every cell, such as the green channel
+    /// of an RGBA image
+    /// If the channel index is not less than N None is returned
+    #[inline]
+    pub fn extract_channel(&self, channel: usize) -> Option<Space<u8>> {
+        if channel >= N {
+            return None;
+        }
+
+        Some(self.convert_with(|cell| cell[channel]))
+    }
+
+    /// Writes the channels of every cell into a flat buffer, reading the space lexicographically
+    /// with the channels of each cell interleaved, which is the tightly packed layout GPUs upload
+    /// Only the first width * height * N bytes are written
+    /// If the buffer is smaller than that an error is returned and it is left untouched
+    #[inline]
+    pub fn pack_into(&self, out: &mut [u8]) -> Result<(), LenMismatch> {
+        let expected = self.data.len() * N;
+
+        if out.len() < expected {
+            return Err(LenMismatch {
+                expected,
+                found: out.len()
+            });
+        }
+
+        for (index, cell) in self.data.iter().enumerate() {
+            out[index * N .. (index + 1) * N].copy_from_slice(cell);
+        }
+
+        Ok(())
+    }
+
+    /// Creates a new space where channel i of every cell is read from the i-th channel space,
+    /// which reverses `extract_channel`
+    /// If the channel spaces do not all have the same dimensions, or there are no channels,
+    /// None is returned
+    #[inline]
+    pub fn combine_channels(channels: &[Space<u8>; N]) -> Option<Space<[u8; N]>> {
+        let first = channels.first()?;
+
+        if !channels.iter().all(|channel| channel.same_shape(first)) {
+            return None;
+        }
+
+        let mut vec = Vec::with_capacity(first.data.len());
+
+        for index in 0 .. first.data.len() {
+            let mut cell = [0; N];
+
+            for (value, channel) in cell.iter_mut().zip(channels.iter()) {
+                *value = channel.data[index];
+            }
+
+            vec.push(cell);
+        }
+
+        Some(Space {
+            data: vec.into_boxed_slice(),
+            width: first.width,
+            height: first.height
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn channels_round_trip_test() {
+        let rgba = Space::new_mapped(|x, y| [x as u8, y as u8, (x * y) as u8, 255], 4, 3);
+
+        let green = rgba.extract_channel(1).unwrap();
+
+        assert_eq!(*green.get(2, 1).unwrap(), 1);
+        assert_eq!(*green.get(3, 2).unwrap(), 2);
+        assert!(rgba.extract_channel(4).is_none());
+
+        let channels = [
+            rgba.extract_channel(0).unwrap(),
+            green,
+            rgba.extract_channel(2).unwrap(),
+            rgba.extract_channel(3).unwrap()
+        ];
+
+        assert_eq!(Space::combine_channels(&channels), Some(rgba));
+
+        let mismatched = [Space::new_flat(0u8, 4, 3), Space::new_flat(0u8, 3, 4)];
+
+        assert_eq!(Space::combine_channels(&mismatched), None);
+    }
+
+    #[test]
+    fn pack_into_test() {
+        let rgba = Space::new_mapped(|x, y| [x as u8, y as u8, 10 + x as u8, 255], 2, 2);
+
+        let mut packed = [0; 17];
+
+        assert_eq!(rgba.pack_into(&mut packed), Ok(()));
+        assert_eq!(packed, [
+            0, 0, 10, 255,   1, 0, 11, 255,
+            0, 1, 10, 255,   1, 1, 11, 255,
+            0
+        ]);
+
+        let mut small = [0; 15];
+
+        assert_eq!(rgba.pack_into(&mut small), Err(LenMismatch { expected: 16, found: 15 }));
+        assert_eq!(small, [0; 15]);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::Space;
+
+/// Simultaneous mutable references to a cell and its four orthogonal neighbors,
+/// which suit stencils that update a cell from the cells around it
+/// A neighbor is None when it would lie outside the space
+#[derive(Debug)]
+pub struct CrossMut<'a, T> {
+    /// The cell the cross is centered on
+    pub center: &'a mut T,
+
+    /// The cell above the center, towards smaller y
+    pub up: Option<&'a mut T>,
+
+    /// The cell below the center, towards larger y
+    pub down: Option<&'a mut T>,
+
+    /// The cell left of the center, towards smaller x
+    pub left: Option<&'a mut T>,
+
+    /// The cell right of the center, towards larger x
+    pub right: Option<&'a mut T>
+}
+
+impl<T> Space<T> {
+    /// Creates mutable references to the cell at an absolute position and its four orthogonal neighbors
+    /// If the position is outside the space None is returned
+    #[inline]
+    pub fn get_cross_mut(&mut self, x: usize, y: usize) -> Option<CrossMut<'_, T>> {
+        let index = self.index_of(x, y)?;
+        let (width, height) = (self.width, self.height);
+
+        let (before, rest) = self.data.split_at_mut(index);
+        let (center, after) = rest.split_first_mut()?;
+
+        // The cells before the center end with the row above it followed by the cells left of it
+        let (up, left) = if y > 0 {
+            let (_, above) = before.split_at_mut(index - width);
+            let (up, between) = above.split_first_mut()?;
+
+            (Some(up), if x > 0 { between.last_mut() } else { None })
+        } else {
+            (None, if x > 0 { before.last_mut() } else { None })
+        };
+
+        // The cells after the center start with the cells right of it followed by the row below it
+        let (beside, below) = after.split_at_mut((width - 1).min(after.len()));
+
+        let right = if x + 1 < width { beside.first_mut() } else { None };
+        let down = if y + 1 < height { below.first_mut() } else { None };
+
+        Some(CrossMut {
+            center,
+
+            up,
+            down,
+            left,
+            right
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_cross_mut_test() {
+        let mut space = Space::new_mapped(|x, y| (y * 3 + x) as i32, 3, 3);
+
+        {
+            let cross = space.get_cross_mut(1, 1).unwrap();
+            let neighbors = [&cross.up, &cross.down, &cross.left, &cross.right];
+            let sum = neighbors.iter().map(|cell| **cell.as_ref().unwrap()).sum::<i32>();
+
+            *cross.center += sum;
+            *cross.up.unwrap() = -1;
+        }
+
+        assert_eq!(space.get(1, 1), Some(&(4 + 1 + 7 + 3 + 5)));
+        assert_eq!(space.get(1, 0), Some(&-1));
+
+        let corner = space.get_cross_mut(2, 2).unwrap();
+
+        assert_eq!((corner.up.as_deref(), corner.left.as_deref()), (Some(&5), Some(&7)));
+        assert!(corner.down.is_none() && corner.right.is_none());
+
+        let mut column = Space::new_mapped(|_, y| y, 1, 3);
+        let cross = column.get_cross_mut(0, 1).unwrap();
+
+        assert_eq!((cross.up.as_deref(), cross.down.as_deref()), (Some(&0), Some(&2)));
+        assert!(cross.left.is_none() && cross.right.is_none());
+
+        assert!(column.get_cross_mut(1, 0).is_none());
+    }
+}
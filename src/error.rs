@@ -0,0 +1,132 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::primitives::Region;
+
+/// The error produced when a coordinate lies outside of a space
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct OutOfBounds {
+    /// The X position that was requested
+    pub x: usize,
+
+    /// The Y position that was requested
+    pub y: usize,
+
+    /// The width (X direction) of the space that was accessed
+    pub width: usize,
+
+    /// The height (Y direction) of the space that was accessed
+    pub height: usize
+}
+
+impl fmt::Display for OutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Position ({}, {}) is outside of space with dimensions {}x{}", self.x, self.y, self.width, self.height)
+    }
+}
+
+impl Error for OutOfBounds {}
+
+/// The error produced when a view covers a region that extends outside of its space
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct BoundsError {
+    /// The region that the view covers
+    pub region: Region,
+
+    /// The width (X direction) of the space that the view is of
+    pub width: usize,
+
+    /// The height (Y direction) of the space that the view is of
+    pub height: usize
+}
+
+impl fmt::Display for BoundsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Region { x, y, width, height } = self.region;
+
+        write!(f, "Region of {}x{} at ({}, {}) extends outside of space with dimensions {}x{}", width, height, x, y, self.width, self.height)
+    }
+}
+
+impl Error for BoundsError {}
+
+/// The error produced when a row or column built for a space
+/// does not have the same length as the others
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct ShapeError {
+    /// The index of the row or column with the wrong length
+    pub index: usize,
+
+    /// The length that every row or column was required to have
+    pub expected: usize,
+
+    /// The length that the row or column actually had
+    pub found: usize
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Line {} has length {} but length {} was expected", self.index, self.found, self.expected)
+    }
+}
+
+impl Error for ShapeError {}
+
+/// The error produced when a slice is split at a coordinate outside of it,
+/// identifying the axis of the offending coordinate
+/// The coordinate and the span of the slice are all absolute
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum SplitError {
+    /// The X coordinate of the split was outside the slice
+    Horizontal {
+        /// The X position that was requested
+        x: usize,
+
+        /// The first X position of the slice
+        start: usize,
+
+        /// The X position just past the end of the slice
+        end: usize
+    },
+
+    /// The Y coordinate of the split was outside the slice
+    Vertical {
+        /// The Y position that was requested
+        y: usize,
+
+        /// The first Y position of the slice
+        start: usize,
+
+        /// The Y position just past the end of the slice
+        end: usize
+    }
+}
+
+impl fmt::Display for SplitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SplitError::Horizontal { x, start, end } => write!(f, "Invalid x value ({}) provided for slice spanning x values {} to {}", x, start, end),
+            SplitError::Vertical { y, start, end } => write!(f, "Invalid y value ({}) provided for slice spanning y values {} to {}", y, start, end)
+        }
+    }
+}
+
+impl Error for SplitError {}
+
+/// The error produced when a buffer is too small to hold the data written into it
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct LenMismatch {
+    /// The length that the buffer was required to have at least
+    pub expected: usize,
+
+    /// The length that the buffer actually had
+    pub found: usize
+}
+
+impl fmt::Display for LenMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Buffer has length {} but length {} was required", self.found, self.expected)
+    }
+}
+
+impl Error for LenMismatch {}
@@ -0,0 +1,328 @@
+use crate::Space;
+use crate::primitives::*;
+
+/// Panics unless the one dimensional kernel has an odd length, so that it has a center cell
+#[inline]
+fn check_kernel_length(kernel: &[f32]) {
+    if kernel.len().is_multiple_of(2) {
+        panic!("Invalid kernel length ({}), kernels must have an odd length", kernel.len());
+    }
+}
+
+/// Convolves a single row or column with a one dimensional kernel of odd length,
+/// handing the result for each position to the output function
+#[inline]
+fn convolve_line<F: FnMut(usize, f32)>(line: &[f32], kernel: &[f32], border: BorderMode, mut output: F) {
+    let center = (kernel.len() / 2) as isize;
+
+    for position in 0 .. line.len() {
+        let mut sum = 0.0;
+
+        for (k, weight) in kernel.iter().enumerate() {
+            let sample = position as isize + k as isize - center;
+
+            if let Some((index, _)) = border.resolve(sample, 0, line.len(), 1) {
+                sum += weight * line[index];
+            }
+        }
+
+        output(position, sum);
+    }
+}
+
+impl Space<f32> {
+    /// Convolves this space with the provided kernel, producing a new space
+    /// with the same dimensions
+    /// The kernel is centered on each cell and is applied without being flipped,
+    /// samples that fall outside the space are handled using the border mode
+    ///
+    /// The kernel must have odd dimensions so that it has a center cell
+    #[inline]
+    pub fn convolve(&self, kernel: &Space<f32>, border: BorderMode) -> Space<f32> {
+        if kernel.width.is_multiple_of(2) || kernel.height.is_multiple_of(2) {
+            panic!("Invalid kernel dimensions ({}x{}), kernels must have odd dimensions", kernel.width, kernel.height);
+        }
+
+        let center_x = (kernel.width / 2) as isize;
+        let center_y = (kernel.height / 2) as isize;
+
+        let mut vec = Vec::with_capacity(self.width * self.height);
+
+        for y in 0 .. self.height {
+            for x in 0 .. self.width {
+                let mut sum = 0.0;
+
+                for ky in 0 .. kernel.height {
+                    for kx in 0 .. kernel.width {
+                        let sample_x = x as isize + kx as isize - center_x;
+                        let sample_y = y as isize + ky as isize - center_y;
+
+                        if let Some((sx, sy)) = border.resolve(sample_x, sample_y, self.width, self.height) {
+                            sum += kernel.data[ky * kernel.width + kx] * self.data[sy * self.width + sx];
+                        }
+                    }
+                }
+
+                vec.push(sum);
+            }
+        }
+
+        Space {
+            data: vec.into_boxed_slice(),
+            width: self.width,
+            height: self.height
+        }
+    }
+
+    /// Convolves this space with a horizontal kernel followed by a vertical kernel,
+    /// which gives the same result as convolving with the 2D kernel that is their product
+    /// for far fewer operations
+    /// Each kernel is centered on the cell and samples that fall outside the space
+    /// are handled using the border mode
+    ///
+    /// Both kernels must have an odd length so that they have a center cell
+    #[inline]
+    pub fn convolve_separable(&self, kx: &[f32], ky: &[f32], border: BorderMode) -> Space<f32> {
+        self.convolve_axis(kx, border, true).convolve_axis(ky, border, false)
+    }
+
+    /// Convolves this space in place with the kernel kx along the X direction
+    /// followed by the kernel ky along the Y direction, giving the same result
+    /// as `convolve_separable` without allocating an intermediate space
+    ///
+    /// Each row and then each column is copied into a single scratch buffer before it is
+    /// overwritten, so only as much extra memory as the larger dimension is used
+    #[inline]
+    pub fn convolve_separable_inplace(&mut self, kx: &[f32], ky: &[f32], border: BorderMode) {
+        check_kernel_length(kx);
+        check_kernel_length(ky);
+
+        let (width, height) = (self.width, self.height);
+
+        let mut line = Vec::with_capacity(width.max(height));
+
+        for y in 0 .. height {
+            line.clear();
+            line.extend_from_slice(&self.data[y * width .. (y + 1) * width]);
+
+            convolve_line(&line, kx, border, |x, sum| self.data[y * width + x] = sum);
+        }
+
+        for x in 0 .. width {
+            line.clear();
+            line.extend((0 .. height).map(|y| self.data[y * width + x]));
+
+            convolve_line(&line, ky, border, |y, sum| self.data[y * width + x] = sum);
+        }
+    }
+
+    /// Convolves this space with a one dimensional kernel along the X or Y direction
+    #[inline]
+    fn convolve_axis(&self, kernel: &[f32], border: BorderMode, horizontal: bool) -> Space<f32> {
+        check_kernel_length(kernel);
+
+        let (width, height) = (self.width, self.height);
+
+        let mut vec = vec![0.0; width * height];
+
+        if horizontal {
+            for y in 0 .. height {
+                convolve_line(&self.data[y * width .. (y + 1) * width], kernel, border, |x, sum| vec[y * width + x] = sum);
+            }
+        } else {
+            let mut line = Vec::with_capacity(height);
+
+            for x in 0 .. width {
+                line.clear();
+                line.extend((0 .. height).map(|y| self.data[y * width + x]));
+
+                convolve_line(&line, kernel, border, |y, sum| vec[y * width + x] = sum);
+            }
+        }
+
+        Space {
+            data: vec.into_boxed_slice(),
+            width,
+            height
+        }
+    }
+
+    /// Computes the Sobel edge magnitude of every cell, `sqrt(gx^2 + gy^2)`,
+    /// where gx and gy are the responses to the horizontal and vertical Sobel kernels
+    #[inline]
+    pub fn sobel_magnitude(&self, border: BorderMode) -> Space<f32> {
+        let horizontal = Space {
+            data: vec![
+                -1.0, 0.0, 1.0,
+                -2.0, 0.0, 2.0,
+                -1.0, 0.0, 1.0
+            ].into_boxed_slice(),
+            width: 3,
+            height: 3
+        };
+
+        let vertical = Space {
+            data: vec![
+                -1.0, -2.0, -1.0,
+                 0.0,  0.0,  0.0,
+                 1.0,  2.0,  1.0
+            ].into_boxed_slice(),
+            width: 3,
+            height: 3
+        };
+
+        let gx = self.convolve(&horizontal, border);
+        let gy = self.convolve(&vertical, border);
+
+        let data = gx.data.iter()
+            .zip(gy.data.iter())
+            .map(|(x, y)| (x * x + y * y).sqrt())
+            .collect::<Vec<_>>();
+
+        Space {
+            data: data.into_boxed_slice(),
+            width: self.width,
+            height: self.height
+        }
+    }
+}
+
+impl Space<u8> {
+    /// Replaces every cell with the largest value in the square window of the given radius around it,
+    /// so the window is 2 * radius + 1 cells on each side
+    /// Samples outside the space are resolved using the border mode
+    #[inline]
+    pub fn grayscale_dilate(&self, radius: usize, border: BorderMode) -> Space<u8> {
+        self.extreme_axis(radius, border, true, u8::max).extreme_axis(radius, border, false, u8::max)
+    }
+
+    /// Replaces every cell with the smallest value in the square window of the given radius around it,
+    /// so the window is 2 * radius + 1 cells on each side
+    /// Samples outside the space are resolved using the border mode
+    #[inline]
+    pub fn grayscale_erode(&self, radius: usize, border: BorderMode) -> Space<u8> {
+        self.extreme_axis(radius, border, true, u8::min).extreme_axis(radius, border, false, u8::min)
+    }
+
+    /// Replaces every cell with the extreme of the samples within the radius along the X or Y direction,
+    /// where the pick function chooses the extreme of two values
+    ///
+    /// The extreme of a square window is the extreme of the extremes of its rows,
+    /// so a pass along each direction covers the whole window
+    #[inline]
+    fn extreme_axis(&self, radius: usize, border: BorderMode, horizontal: bool, pick: fn(u8, u8) -> u8) -> Space<u8> {
+        let radius = radius as isize;
+
+        let mut vec = Vec::with_capacity(self.width * self.height);
+
+        for y in 0 .. self.height {
+            for x in 0 .. self.width {
+                let mut extreme = self.data[y * self.width + x];
+
+                for offset in -radius ..= radius {
+                    let (sample_x, sample_y) = if horizontal {
+                        (x as isize + offset, y as isize)
+                    } else {
+                        (x as isize, y as isize + offset)
+                    };
+
+                    if let Some((sx, sy)) = border.resolve(sample_x, sample_y, self.width, self.height) {
+                        extreme = pick(extreme, self.data[sy * self.width + sx]);
+                    }
+                }
+
+                vec.push(extreme);
+            }
+        }
+
+        Space {
+            data: vec.into_boxed_slice(),
+            width: self.width,
+            height: self.height
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convolve_identity_test() {
+        let space = Space::new_mapped(|x, y| (x * 3 + y) as f32, 5, 4);
+        let identity = Space::new_mapped(|x, y| if x == 1 && y == 1 { 1.0 } else { 0.0 }, 3, 3);
+
+        assert_eq!(space.convolve(&identity, BorderMode::Bounded), space);
+    }
+
+    #[test]
+    fn separable_box_test() {
+        let space = Space::new_mapped(|x, y| ((x * 7 + y * 3) % 11) as f32, 9, 6);
+
+        let box_1d = [1.0 / 3.0; 3];
+        let box_2d = Space::new_flat(1.0 / 9.0, 3, 3);
+
+        for border in [BorderMode::Bounded, BorderMode::Clamped, BorderMode::Wrapping].iter() {
+            let separable = space.convolve_separable(&box_1d, &box_1d, *border);
+            let full = space.convolve(&box_2d, *border);
+
+            assert!(separable.iter().zip(full.iter()).all(|(a, b)| (a - b).abs() < 1e-5));
+        }
+    }
+
+    #[test]
+    fn sobel_step_edge_test() {
+        let space = Space::new_mapped(|x, _| if x < 4 { 0.0 } else { 1.0 }, 8, 8);
+
+        let edges = space.sobel_magnitude(BorderMode::Clamped);
+
+        for y in 0 .. 8 {
+            assert!(*edges.get(3, y).unwrap() > 3.0);
+            assert!(*edges.get(4, y).unwrap() > 3.0);
+
+            assert!(edges.get(1, y).unwrap().abs() < 1e-6);
+            assert!(edges.get(6, y).unwrap().abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn grayscale_dilate_test() {
+        let mut space = Space::new_flat(10u8, 6, 5);
+        space.set(2, 2, 200);
+
+        let dilated = space.grayscale_dilate(1, BorderMode::Clamped);
+
+        for y in 0 .. 5 {
+            for x in 0 .. 6 {
+                let expected = if (1 ..= 3).contains(&x) && (1 ..= 3).contains(&y) { 200 } else { 10 };
+
+                assert_eq!(*dilated.get(x, y).unwrap(), expected);
+            }
+        }
+
+        assert_eq!(dilated.grayscale_erode(1, BorderMode::Clamped), space);
+        assert!(dilated.grayscale_erode(2, BorderMode::Bounded).iter().all(|v| *v == 10));
+    }
+
+    #[test]
+    fn convolve_separable_inplace_test() {
+        let space = Space::new_mapped(|x, y| ((x * 7 + y * 13) % 11) as f32, 9, 6);
+        let kx = [0.25, 0.5, 0.25];
+        let ky = [0.1, 0.2, 0.4, 0.2, 0.1];
+
+        for border in [BorderMode::Bounded, BorderMode::Clamped, BorderMode::Wrapping].iter() {
+            let mut in_place = space.clone();
+            in_place.convolve_separable_inplace(&kx, &ky, *border);
+
+            assert_eq!(in_place, space.convolve_separable(&kx, &ky, *border));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn convolve_separable_inplace_even_kernel_test() {
+        let mut space = Space::new_flat(0.0f32, 4, 0);
+
+        space.convolve_separable_inplace(&[1.0], &[0.5, 0.5], BorderMode::Clamped);
+    }
+}
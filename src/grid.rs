@@ -0,0 +1,122 @@
+use crate::{Space, SubSpace, SpaceRef};
+use crate::primitives::*;
+
+/// A Grid is any read only rectangular 2 dimensional array,
+/// which lets an algorithm be written once for owned spaces and borrowed views alike
+/// Positions are always relative to the top left cell of the grid
+pub trait Grid {
+    /// The type of the cells of the grid
+    type Item;
+
+    /// The width (X direction) of the grid
+    fn width(&self) -> usize;
+
+    /// The height (Y direction) of the grid
+    fn height(&self) -> usize;
+
+    /// Creates an immutable reference to the cell at a position
+    /// If the position is outside the grid None is returned
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Item>;
+
+    /// Finds the position of the first cell that satisfies the predicate, reading the grid lexicographically
+    /// If no cell satisfies the predicate None is returned
+    #[inline]
+    fn find<F: Fn(&Self::Item) -> bool>(&self, pred: F) -> Option<(usize, usize)> {
+        let width = self.width();
+
+        (0 .. self.height())
+            .flat_map(|y| (0 .. width).map(move |x| (x, y)))
+            .find(|(x, y)| self.get(*x, *y).is_some_and(&pred))
+    }
+
+    /// Counts the cells that satisfy the predicate
+    #[inline]
+    fn count<F: Fn(&Self::Item) -> bool>(&self, pred: F) -> usize {
+        let width = self.width();
+
+        (0 .. self.height())
+            .flat_map(|y| (0 .. width).map(move |x| (x, y)))
+            .filter(|(x, y)| self.get(*x, *y).is_some_and(&pred))
+            .count()
+    }
+}
+
+impl<T> Grid for Space<T> {
+    type Item = T;
+
+    #[inline]
+    fn width(&self) -> usize {
+        Space::width(self)
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        Space::height(self)
+    }
+
+    #[inline]
+    fn get(&self, x: usize, y: usize) -> Option<&T> {
+        Space::get(self, x, y)
+    }
+}
+
+impl<'a, T> Grid for SubSpace<'a, T> {
+    type Item = T;
+
+    #[inline]
+    fn width(&self) -> usize {
+        SubSpace::width(self)
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        SubSpace::height(self)
+    }
+
+    #[inline]
+    fn get(&self, x: usize, y: usize) -> Option<&T> {
+        SubSpace::get(self, PositioningType::Relative, x, y)
+    }
+}
+
+impl<'a, T> Grid for SpaceRef<'a, T> {
+    type Item = T;
+
+    #[inline]
+    fn width(&self) -> usize {
+        SpaceRef::width(self)
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        SpaceRef::height(self)
+    }
+
+    #[inline]
+    fn get(&self, x: usize, y: usize) -> Option<&T> {
+        SpaceRef::get(self, x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count_true<G: Grid<Item = bool>>(grid: &G) -> usize {
+        grid.count(|cell| *cell)
+    }
+
+    #[test]
+    fn grid_count_test() {
+        let space = Space::new_mapped(|x, y| x == y || x == 4, 5, 5);
+        let subspace = space.as_subspace_at(1, 0, 3, 2).unwrap();
+
+        assert_eq!(count_true(&space), 9);
+        assert_eq!(count_true(&subspace), 1);
+        assert_eq!(count_true(&space.as_space_ref()), 9);
+
+        assert_eq!(Grid::find(&space, |cell| *cell), Some((0, 0)));
+        assert_eq!(Grid::find(&subspace, |cell| *cell), Some((0, 1)));
+        assert_eq!(Grid::find(&subspace, |cell| !*cell), Some((0, 0)));
+    }
+}
@@ -1,11 +1,56 @@
 mod primitives;
+mod error;
 
 mod subspace;
 mod subspace_mut;
+mod views;
+mod owned_slice;
+mod space_ref;
+mod grid;
+mod cross;
+mod builder;
+
+mod bit_space;
+mod sparse;
+
+mod analysis;
+mod filter;
+mod numeric;
+mod channels;
+mod arrays;
+mod neighbors;
+mod transform;
+mod rows;
+mod region;
+mod shuffle;
+
+#[cfg(feature = "rayon")]
+mod parallel;
 
 pub use primitives::*;
+pub use error::*;
 pub use subspace::*;
 pub use subspace_mut::*;
+pub use views::*;
+pub use owned_slice::*;
+pub use space_ref::*;
+pub use grid::*;
+pub use cross::*;
+pub use builder::*;
+
+pub use bit_space::*;
+pub use sparse::*;
+
+/// Converts a buffer whose cells have all been written into an initialized boxed slice
+///
+/// # Safety
+/// Every cell of the buffer must have been initialized
+#[inline]
+pub(crate) unsafe fn assume_init_buffer<T>(buffer: Vec<std::mem::MaybeUninit<T>>) -> Box<[T]> {
+    let mut buffer = std::mem::ManuallyDrop::new(buffer);
+
+    Vec::from_raw_parts(buffer.as_mut_ptr() as *mut T, buffer.len(), buffer.capacity()).into_boxed_slice()
+}
 
 /// A Space represents a rectangular 2 dimensional array of contiguous
 /// dynamically allocated memory
@@ -56,6 +101,42 @@ impl<T> Space<T> {
         }
     }
 
+    /// Creates a space where the cell at (x, y) is a when x + y is even and b otherwise,
+    /// with the provided dimensions
+    #[inline]
+    pub fn checkerboard(a: T, b: T, width: usize, height: usize) -> Self
+        where T: Clone {
+
+        Space::checkerboard_blocks(a, b, 1, width, height)
+    }
+
+    /// Creates a space tiled with squares of a and b that are block cells wide and high,
+    /// alternating like a checkerboard with a in the top left square,
+    /// with the provided dimensions
+    /// Panics if the block size is zero
+    #[inline]
+    pub fn checkerboard_blocks(a: T, b: T, block: usize, width: usize, height: usize) -> Self
+        where T: Clone {
+
+        if block == 0 {
+            panic!("Invalid block size ({}), blocks must be at least 1 cell wide", block);
+        }
+
+        let mut vec = Vec::with_capacity(width * height);
+
+        for y in 0 .. height {
+            for x in 0 .. width {
+                vec.push(if (x / block + y / block).is_multiple_of(2) { a.clone() } else { b.clone() });
+            }
+        }
+
+        Space {
+            data: vec.into_boxed_slice(),
+            width,
+            height
+        }
+    }
+
     /// Creates a space by iterating through the given iterator
     /// This operation fails if the provided iterator does not contain enough data
     #[inline] 
@@ -119,14 +200,66 @@ impl<T> Space<T> {
         self.height
     }
 
+    /// The number of cells in the Space
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Whether the Space has no cells, which is the case when either dimension is zero
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Checks that the backing memory holds exactly width * height cells,
+    /// and that the product of the dimensions does not overflow
+    /// Every constructor upholds this, so false means the space has been corrupted
+    #[cfg(debug_assertions)]
+    #[inline]
+    pub fn verify_invariants(&self) -> bool {
+        self.width.checked_mul(self.height) == Some(self.data.len())
+    }
+
+    /// The index into the linear memory of the space that stores
+    /// the element at an absolute position
+    /// If the position specified is outside the space None is returned
+    #[inline]
+    pub fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// The absolute position of the element stored at an index
+    /// into the linear memory of the space
+    /// If the index is past the end of the space None is returned
+    #[inline]
+    pub fn coords_of(&self, index: usize) -> Option<(usize, usize)> {
+        if index < self.data.len() {
+            Some((index % self.width, index / self.width))
+        } else {
+            None
+        }
+    }
+
+    /// Checks whether another space, which may hold a different type,
+    /// has the same width and height as this one
+    #[inline]
+    pub fn same_shape<U>(&self, other: &Space<U>) -> bool {
+        self.width == other.width && self.height == other.height
+    }
+
     /// Creates an immutable reference to an element at an absolute position
     /// in the space
     /// If the position specified is outside the space None is returned
     #[inline]
     pub fn get(&self, x: usize, y: usize) -> Option<&T> {
-        let index = y * self.width + x;
+        let index = self.index_of(x, y)?;
 
-        self.data.get(index)
+        Some(&self.data[index])
     }
 
     /// Creates a mutable reference to an element at an absolute position
@@ -134,18 +267,34 @@ impl<T> Space<T> {
     /// If the position specified is outside the space None is returned
     #[inline]
     pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
-        let index = y * self.width + x;
+        let index = self.index_of(x, y)?;
+
+        Some(&mut self.data[index])
+    }
+
+    /// Creates an immutable reference to an element at an absolute position
+    /// in the space
+    /// If the position specified is outside the space an error describing
+    /// the position and the dimensions of the space is returned
+    #[inline]
+    pub fn get_checked(&self, x: usize, y: usize) -> Result<&T, OutOfBounds> {
+        if x >= self.width || y >= self.height {
+            return Err(OutOfBounds {
+                x,
+                y,
+                width: self.width,
+                height: self.height
+            });
+        }
 
-        self.data.get_mut(index)
+        Ok(&self.data[y * self.width + x])
     }
 
     /// Sets the value for the specified absolute position in the space
     /// If the position specified is outside the space false is returned
     #[inline]
     pub fn set(&mut self, x: usize, y: usize, value: T) -> bool {
-        let index = y * self.width + x;
-
-        if index < self.data.len() {
+        if let Some(index) = self.index_of(x, y) {
             self.data[index] = value;
             true
         } else {
@@ -153,6 +302,47 @@ impl<T> Space<T> {
         }
     }
 
+    /// Exchanges the contents of this space with another space of the same dimensions
+    /// by swapping their backing memory, which never copies or allocates
+    /// If the dimensions differ neither space is changed and false is returned
+    #[inline]
+    pub fn swap_buffers(&mut self, other: &mut Space<T>) -> bool {
+        if !self.same_shape(other) {
+            return false;
+        }
+
+        std::mem::swap(&mut self.data, &mut other.data);
+
+        true
+    }
+
+    /// Creates a new space with the same dimensions where each cell is
+    /// the result of applying the function to the cell at the same position in this space
+    #[inline]
+    pub fn convert_with<U, F: Fn(&T) -> U>(&self, func: F) -> Space<U> {
+        Space {
+            data: self.data.iter().map(func).collect::<Vec<_>>().into_boxed_slice(),
+            width: self.width,
+            height: self.height
+        }
+    }
+
+    /// Overwrites every cell of the destination with the result of applying the function
+    /// to the cell at the same position in this space, reusing the destination's memory
+    /// If the dimensions differ the destination is left untouched and false is returned
+    #[inline]
+    pub fn map_into<U, F: FnMut(&T) -> U>(&self, dst: &mut Space<U>, mut func: F) -> bool {
+        if !self.same_shape(dst) {
+            return false;
+        }
+
+        for (target, value) in dst.data.iter_mut().zip(self.data.iter()) {
+            *target = func(value);
+        }
+
+        true
+    }
+
     /// Sets the value for every position in the space
     /// based on its position
     /// 
@@ -188,7 +378,7 @@ impl<T> Space<T> {
     }
 
     #[inline]
-    pub fn iter(&self) -> std::slice::Iter<T> {
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
         self.data.iter()
     } 
 }
@@ -223,6 +413,146 @@ mod tests {
         }
     }
 
+    #[test]
+    fn checkerboard_test() {
+        let board = Space::checkerboard('#', '.', 3, 3);
+
+        assert_eq!(board.iter().collect::<String>(), "#.#.#.#.#");
+
+        let blocks = Space::checkerboard_blocks(1, 0, 2, 5, 3);
+
+        assert_eq!(blocks.iter().copied().collect::<Vec<_>>(), vec![
+            1, 1, 0, 0, 1,
+            1, 1, 0, 0, 1,
+            0, 0, 1, 1, 0
+        ]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn checkerboard_zero_block_test() {
+        Space::checkerboard_blocks(1, 0, 0, 2, 2);
+    }
+
+    #[test]
+    fn get_checked_test() {
+        let space = Space::new_mapped(|x, y| (x, y), 4, 3);
+
+        assert_eq!(space.get_checked(3, 2), Ok(&(3, 2)));
+
+        let error = space.get_checked(4, 1).unwrap_err();
+
+        assert_eq!(error, OutOfBounds { x: 4, y: 1, width: 4, height: 3 });
+        assert_eq!(Space::new_flat(0u8, 0, 0).get_checked(0, 0).unwrap_err().width, 0);
+    }
+
+    #[test]
+    fn index_round_trip_test() {
+        let space = Space::new_flat(0u8, 7, 3);
+
+        for y in 0 .. 3 {
+            for x in 0 .. 7 {
+                let index = space.index_of(x, y).unwrap();
+
+                assert_eq!(space.coords_of(index), Some((x, y)));
+            }
+        }
+
+        assert_eq!(space.index_of(6, 2), Some(20));
+        assert_eq!(space.coords_of(20), Some((6, 2)));
+    }
+
+    #[test]
+    fn index_out_of_bounds_test() {
+        let mut space = Space::new_flat(0u8, 7, 3);
+
+        assert_eq!(space.index_of(7, 0), None);
+        assert_eq!(space.index_of(0, 3), None);
+        assert_eq!(space.coords_of(21), None);
+        assert_eq!(space.get(7, 0), None);
+        assert!(!space.set(7, 1, 1));
+        assert_eq!(Space::new_flat(0u8, 0, 4).coords_of(0), None);
+    }
+
+    #[test]
+    fn len_test() {
+        let space = Space::new_flat(0u8, 4, 3);
+        let empty = Space::new_flat(0u8, 0, 5);
+
+        assert_eq!(space.len(), 12);
+        assert!(!space.is_empty());
+
+        assert_eq!(empty.len(), 0);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn verify_invariants_test() {
+        assert!(Space::new_flat(0u8, 4, 3).verify_invariants());
+        assert!(Space::new_flat(0u8, 0, 7).verify_invariants());
+
+        let short = Space {
+            data: vec![0u8; 11].into_boxed_slice(),
+            width: 4,
+            height: 3
+        };
+
+        let overflowing = Space {
+            data: Vec::<u8>::new().into_boxed_slice(),
+            width: usize::MAX,
+            height: 2
+        };
+
+        assert!(!short.verify_invariants());
+        assert!(!overflowing.verify_invariants());
+    }
+
+    #[test]
+    fn swap_buffers_test() {
+        let mut front = Space::new_flat(1u8, 4, 3);
+        let mut back = Space::new_flat(2u8, 4, 3);
+
+        let (front_ptr, back_ptr) = (front.data.as_ptr(), back.data.as_ptr());
+
+        assert!(front.swap_buffers(&mut back));
+        assert_eq!(front.data.as_ptr(), back_ptr);
+        assert_eq!(back.data.as_ptr(), front_ptr);
+        assert!(front.iter().all(|v| *v == 2));
+
+        let mut other = Space::new_flat(3u8, 3, 4);
+
+        assert!(!front.swap_buffers(&mut other));
+        assert!(front.iter().all(|v| *v == 2));
+        assert!(other.iter().all(|v| *v == 3));
+    }
+
+    #[test]
+    fn map_into_test() {
+        let source = Space::new_mapped(|x, y| (y * 8 + x) as u8, 8, 4);
+        let mut destination = Space::new_flat(0u16, 8, 4);
+
+        let pointer = destination.data.as_ptr();
+
+        assert!(source.map_into(&mut destination, |v| *v as u16 * 300));
+        assert_eq!(*destination.get(7, 3).unwrap(), 31 * 300);
+
+        assert!(source.map_into(&mut destination, |v| *v as u16 + 1));
+        assert_eq!(*destination.get(7, 3).unwrap(), 32);
+        assert_eq!(destination.data.as_ptr(), pointer);
+
+        assert!(!source.map_into(&mut Space::new_flat(0u16, 4, 8), |v| *v as u16));
+    }
+
+    #[test]
+    fn same_shape_test() {
+        let integers = Space::new_flat(0i32, 4, 3);
+
+        assert!(integers.same_shape(&Space::new_flat(0.0f32, 4, 3)));
+        assert!(!integers.same_shape(&Space::new_flat(0.0f32, 3, 4)));
+        assert!(!integers.same_shape(&Space::new_flat(0.0f32, 4, 2)));
+    }
+
     #[test]
     fn from_iter_test() {
 
@@ -0,0 +1,504 @@
+use std::ops::Add;
+
+use crate::{Space, SubSpace};
+use crate::primitives::*;
+
+impl<'a, T> SubSpace<'a, T> {
+    /// Counts how many neighbors of the cell at the given relative position satisfy the predicate
+    /// Neighbors outside this SubSpace are resolved using the border mode,
+    /// so with Bounded they are skipped, while with Clamped or Wrapping they are
+    /// counted once for every offset that resolves to them
+    #[inline]
+    pub fn count_neighbors<F: Fn(&T) -> bool>(&self, x: usize, y: usize, conn: Connectivity, border: BorderMode, pred: F) -> usize {
+        conn.offsets().iter()
+            .filter_map(|(dx, dy)| border.resolve(x as isize + dx, y as isize + dy, self.width(), self.height()))
+            .filter_map(|(nx, ny)| self.get(PositioningType::Relative, nx, ny))
+            .filter(|value| pred(value))
+            .count()
+    }
+
+    /// Finds the relative position of the closest cell to the given relative position
+    /// that satisfies the predicate, including the cell at that position itself
+    /// Cells are searched in square rings of increasing size, on ties the cell found first
+    /// in a ring, reading lexicographically, wins
+    /// If no cell satisfies the predicate None is returned
+    #[inline]
+    pub fn nearest<F: Fn(&T) -> bool>(&self, from_x: usize, from_y: usize, metric: Metric, pred: F) -> Option<(usize, usize)> {
+        let (width, height) = (self.width(), self.height());
+
+        let max_radius = width.max(height) + from_x.max(from_y);
+        let mut best: Option<(usize, (usize, usize))> = None;
+
+        for radius in 0 ..= max_radius {
+            // Every cell in this ring and the ones after it is at least the radius away
+            if let Some((rank, _)) = best {
+                if metric.rank(radius, 0) >= rank {
+                    break;
+                }
+            }
+
+            let top = from_y as isize - radius as isize;
+            let left = from_x as isize - radius as isize;
+
+            for y in top .. top + 2 * radius as isize + 1 {
+                let on_edge = y == top || y == top + 2 * radius as isize;
+                let step = if on_edge || radius == 0 { 1 } else { 2 * radius };
+
+                for x in (left .. left + 2 * radius as isize + 1).step_by(step) {
+                    if x < 0 || y < 0 || x as usize >= width || y as usize >= height {
+                        continue;
+                    }
+
+                    let (x, y) = (x as usize, y as usize);
+
+                    if !self.get(PositioningType::Relative, x, y).is_some_and(&pred) {
+                        continue;
+                    }
+
+                    let rank = metric.rank(x.abs_diff(from_x), y.abs_diff(from_y));
+
+                    if best.is_none_or(|(best_rank, _)| rank < best_rank) {
+                        best = Some((rank, (x, y)));
+                    }
+                }
+            }
+        }
+
+        best.map(|(_, position)| position)
+    }
+}
+
+impl<T> Space<T> {
+    /// Creates a new space where every cell is the sum of the values of its neighbors,
+    /// which is a building block for diffusion and Laplacian filters
+    /// Neighbors outside the space are resolved using the border mode,
+    /// so with Bounded they are skipped and contribute nothing to the sum
+    #[inline]
+    pub fn neighbor_sum(&self, conn: Connectivity, border: BorderMode) -> Space<T>
+        where T: Add<Output = T> + Default + Copy {
+
+        let mut vec = Vec::with_capacity(self.width * self.height);
+
+        for y in 0 .. self.height {
+            for x in 0 .. self.width {
+                let sum = conn.offsets().iter()
+                    .filter_map(|(dx, dy)| border.resolve(x as isize + dx, y as isize + dy, self.width, self.height))
+                    .fold(T::default(), |sum, (nx, ny)| sum + self.data[ny * self.width + nx]);
+
+                vec.push(sum);
+            }
+        }
+
+        Space {
+            data: vec.into_boxed_slice(),
+            width: self.width,
+            height: self.height
+        }
+    }
+
+    /// Creates a new space with the same dimensions where every cell is computed from the window
+    /// of the given dimensions around it, the window holds its cells lexicographically
+    /// and the cell itself is at (window width / 2, window height / 2)
+    /// Samples outside the space are resolved using the border mode,
+    /// so with Bounded they are skipped and windows near an edge hold fewer cells
+    #[inline]
+    pub fn map_windowed<U, F>(&self, window_width: usize, window_height: usize, border: BorderMode, func: F) -> Space<U>
+        where F: Fn(&[&T]) -> U {
+
+        let (left, top) = ((window_width / 2) as isize, (window_height / 2) as isize);
+
+        let mut window = Vec::with_capacity(window_width * window_height);
+        let mut vec = Vec::with_capacity(self.width * self.height);
+
+        for y in 0 .. self.height as isize {
+            for x in 0 .. self.width as isize {
+                window.clear();
+
+                for sample_y in y - top .. y - top + window_height as isize {
+                    for sample_x in x - left .. x - left + window_width as isize {
+                        if let Some((sx, sy)) = border.resolve(sample_x, sample_y, self.width, self.height) {
+                            window.push(&self.data[sy * self.width + sx]);
+                        }
+                    }
+                }
+
+                vec.push(func(&window));
+            }
+        }
+
+        Space {
+            data: vec.into_boxed_slice(),
+            width: self.width,
+            height: self.height
+        }
+    }
+
+    /// Creates a new space by reducing the square window of the given radius around each cell
+    /// The function is given the window and the relative position of the center cell inside it
+    ///
+    /// With Bounded the windows near an edge are cut down to the cells inside the space,
+    /// so the center is not always in the middle of the window
+    /// With Clamped or Wrapping every window has a side of 2 * radius + 1 with the center
+    /// in the middle, the cells outside the space are resolved into a padded copy of the space
+    #[inline]
+    pub fn map_neighbors<U, F>(&self, radius: usize, border: BorderMode, func: F) -> Space<U>
+        where
+            T: Clone,
+            F: Fn(&SubSpace<'_, T>, usize, usize) -> U {
+
+        let mut vec = Vec::with_capacity(self.width * self.height);
+
+        if border == BorderMode::Bounded {
+            for y in 0 .. self.height {
+                for x in 0 .. self.width {
+                    let (left, top) = (x.saturating_sub(radius), y.saturating_sub(radius));
+                    let right = (x + radius + 1).min(self.width);
+                    let bottom = (y + radius + 1).min(self.height);
+
+                    let window = self.as_subspace_at(left, top, right - left, bottom - top).unwrap();
+
+                    vec.push(func(&window, x - left, y - top));
+                }
+            }
+        } else if !self.is_empty() {
+            let side = 2 * radius + 1;
+
+            let (padded_width, padded_height) = (self.width + 2 * radius, self.height + 2 * radius);
+            let mut cells = Vec::with_capacity(padded_width * padded_height);
+
+            for y in 0 .. padded_height as isize {
+                for x in 0 .. padded_width as isize {
+                    // Clamped and Wrapping resolve every coordinate of a non-empty space
+                    let (sx, sy) = border.resolve(x - radius as isize, y - radius as isize, self.width, self.height).unwrap();
+
+                    cells.push(self.data[sy * self.width + sx].clone());
+                }
+            }
+
+            let padded = Space {
+                data: cells.into_boxed_slice(),
+                width: padded_width,
+                height: padded_height
+            };
+
+            for y in 0 .. self.height {
+                for x in 0 .. self.width {
+                    let window = padded.as_subspace_at(x, y, side, side).unwrap();
+
+                    vec.push(func(&window, radius, radius));
+                }
+            }
+        }
+
+        Space {
+            data: vec.into_boxed_slice(),
+            width: self.width,
+            height: self.height
+        }
+    }
+}
+
+impl Space<bool> {
+    /// Creates an iterator over the positions of the neighbors of a cell that are walkable,
+    /// a cell is walkable when it is true
+    /// Neighbors outside the space are never yielded
+    #[inline]
+    pub fn walkable_neighbors(&self, x: usize, y: usize, conn: Connectivity) -> impl Iterator<Item = (usize, usize)> + '_ {
+        conn.offsets().iter()
+            .filter_map(move |(dx, dy)| BorderMode::Bounded.resolve(x as isize + dx, y as isize + dy, self.width, self.height))
+            .filter(move |(nx, ny)| self.data[ny * self.width + nx])
+    }
+
+    /// Counts the number of distinct connected regions of true cells
+    ///
+    /// This makes a single pass with a union-find over the cells, which is cheaper
+    /// than labelling every region when only the count is needed
+    #[inline]
+    pub fn count_components(&self, conn: Connectivity) -> usize {
+        fn find(parents: &mut [usize], mut index: usize) -> usize {
+            while parents[index] != index {
+                parents[index] = parents[parents[index]];
+                index = parents[index];
+            }
+
+            index
+        }
+
+        let mut parents = (0 .. self.data.len()).collect::<Vec<_>>();
+        let mut components = 0;
+
+        for y in 0 .. self.height {
+            for x in 0 .. self.width {
+                let index = y * self.width + x;
+
+                if !self.data[index] {
+                    continue;
+                }
+
+                components += 1;
+
+                // Only neighbors that come earlier in the pass have been visited
+                let visited = conn.offsets().iter().filter(|(dx, dy)| *dy < 0 || (*dy == 0 && *dx < 0));
+
+                for (dx, dy) in visited {
+                    let neighbor = BorderMode::Bounded.resolve(x as isize + dx, y as isize + dy, self.width, self.height);
+
+                    if let Some((nx, ny)) = neighbor {
+                        let neighbor = ny * self.width + nx;
+
+                        if !self.data[neighbor] {
+                            continue;
+                        }
+
+                        let (root, neighbor_root) = (find(&mut parents, index), find(&mut parents, neighbor));
+
+                        if root != neighbor_root {
+                            parents[root] = neighbor_root;
+                            components -= 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        components
+    }
+}
+
+impl<T> Space<Option<T>> {
+    /// Creates a new space where every None cell takes the value of the nearest Some cell,
+    /// which fills the gaps between sparse samples, see `nearest` for how ties are broken
+    /// Distances are Chebyshev, Some cells are kept as they are,
+    /// and if there are no Some cells at all every cell stays None
+    #[inline]
+    pub fn fill_none_nearest(&self) -> Space<Option<T>>
+        where T: Clone {
+
+        if self.data.iter().all(Option::is_none) {
+            return self.clone();
+        }
+
+        let subspace = self.as_subspace();
+        let mut vec = Vec::with_capacity(self.data.len());
+
+        for y in 0 .. self.height {
+            for x in 0 .. self.width {
+                let sample = subspace.nearest(x, y, Metric::Chebyshev, Option::is_some);
+
+                vec.push(sample.and_then(|(sample_x, sample_y)| self.data[sample_y * self.width + sample_x].clone()));
+            }
+        }
+
+        Space {
+            data: vec.into_boxed_slice(),
+            width: self.width,
+            height: self.height
+        }
+    }
+}
+
+impl Space<u32> {
+    /// Renumbers the distinct non zero labels of the space to 1 ..= k keeping their order,
+    /// so that labels left sparse by merging regions can index a dense table
+    /// Zero is kept as the background label, and k is returned
+    #[inline]
+    pub fn compact_labels(&mut self) -> usize {
+        let mut labels = self.data.iter().copied().filter(|label| *label != 0).collect::<Vec<_>>();
+
+        labels.sort_unstable();
+        labels.dedup();
+
+        for label in self.data.iter_mut().filter(|label| **label != 0) {
+            // Every non zero label was collected, so the search always succeeds
+            *label = labels.binary_search(label).map_or(0, |index| index as u32 + 1);
+        }
+
+        labels.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_neighbors_corner_test() {
+        // Live cells next to the top left corner and along the opposite edges
+        let space = Space::new_mapped(|x, y| (x, y) == (1, 0) || (x, y) == (1, 1) || x == 4 || y == 4, 5, 5);
+        let subspace = space.as_subspace();
+
+        let bounded = subspace.count_neighbors(0, 0, Connectivity::Eight, BorderMode::Bounded, |v| *v);
+        let wrapped = subspace.count_neighbors(0, 0, Connectivity::Eight, BorderMode::Wrapping, |v| *v);
+        let orthogonal = subspace.count_neighbors(0, 0, Connectivity::Four, BorderMode::Wrapping, |v| *v);
+
+        assert_eq!(bounded, 2);
+        assert_eq!(wrapped, 7);
+        assert_eq!(orthogonal, 3);
+    }
+
+    #[test]
+    fn walkable_neighbors_test() {
+        // . # .
+        // . . #
+        // # . .
+        let walls = [(1, 0), (2, 1), (0, 2)];
+        let mut maze = Space::new_flat(true, 3, 3);
+
+        for (x, y) in walls.iter() {
+            maze.set(*x, *y, false);
+        }
+
+        let orthogonal = maze.walkable_neighbors(1, 1, Connectivity::Four).collect::<Vec<_>>();
+        let diagonal = maze.walkable_neighbors(1, 1, Connectivity::Eight).collect::<Vec<_>>();
+        let corner = maze.walkable_neighbors(0, 0, Connectivity::Eight).collect::<Vec<_>>();
+
+        assert_eq!(orthogonal, vec![(0, 1), (1, 2)]);
+        assert_eq!(diagonal, vec![(0, 0), (2, 0), (0, 1), (1, 2), (2, 2)]);
+        assert_eq!(corner, vec![(0, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn nearest_test() {
+        let mut space = Space::new_flat(false, 6, 6);
+
+        space.set(3, 0, true);
+        space.set(2, 2, true);
+        space.set(5, 5, true);
+
+        let subspace = space.as_subspace();
+        let is_set = |v: &bool| *v;
+
+        assert_eq!(subspace.nearest(0, 0, Metric::Manhattan, is_set), Some((3, 0)));
+        assert_eq!(subspace.nearest(0, 0, Metric::Chebyshev, is_set), Some((2, 2)));
+        assert_eq!(subspace.nearest(0, 0, Metric::Euclidean, is_set), Some((2, 2)));
+        assert_eq!(subspace.nearest(5, 4, Metric::Euclidean, is_set), Some((5, 5)));
+        assert_eq!(subspace.nearest(2, 2, Metric::Manhattan, is_set), Some((2, 2)));
+
+        assert_eq!(Space::new_flat(false, 3, 3).as_subspace().nearest(1, 1, Metric::Chebyshev, is_set), None);
+    }
+
+    #[test]
+    fn count_components_test() {
+        // # # . . #
+        // . # . . #
+        // . . . # .
+        // # . . # .
+        let cells = [
+            1, 1, 0, 0, 1,
+            0, 1, 0, 0, 1,
+            0, 0, 0, 1, 0,
+            1, 0, 0, 1, 0
+        ];
+        let space = Space::from_iter(&mut cells.iter().map(|cell| *cell == 1), 5, 4).unwrap();
+
+        assert_eq!(space.count_components(Connectivity::Four), 4);
+        assert_eq!(space.count_components(Connectivity::Eight), 3);
+        assert_eq!(Space::new_flat(false, 3, 3).count_components(Connectivity::Eight), 0);
+        assert_eq!(Space::new_flat(true, 3, 3).count_components(Connectivity::Four), 1);
+    }
+
+    #[test]
+    fn fill_none_nearest_test() {
+        let mut samples = Space::new_flat(None, 6, 3);
+        samples.set(0, 1, Some('a'));
+        samples.set(5, 0, Some('b'));
+
+        let filled = samples.fill_none_nearest();
+        let rows = filled.iter().map(|cell| cell.unwrap()).collect::<String>();
+
+        assert_eq!(rows, "aaabbbaaabbbaaabbb");
+
+        let empty = Space::<Option<u8>>::new_flat(None, 3, 3);
+
+        assert_eq!(empty.fill_none_nearest(), empty);
+    }
+
+    #[test]
+    fn compact_labels_test() {
+        let mut labels = Space::from_iter(&mut [7u32, 7, 0, 3, 0, 3, 7, 0, 3].iter().copied(), 3, 3).unwrap();
+
+        assert_eq!(labels.compact_labels(), 2);
+        assert_eq!(labels.iter().copied().collect::<Vec<_>>(), vec![2, 2, 0, 1, 0, 1, 2, 0, 1]);
+
+        assert_eq!(Space::new_flat(0u32, 2, 2).compact_labels(), 0);
+    }
+
+    #[test]
+    fn map_neighbors_median_test() {
+        // 1 9 2 8
+        // 7 3 6 4
+        // 5 0 5 1
+        let values = [1, 9, 2, 8, 7, 3, 6, 4, 5, 0, 5, 1];
+        let space = Space::from_iter(&mut values.iter().copied(), 4, 3).unwrap();
+
+        let median = |window: &SubSpace<'_, u8>, _: usize, _: usize| {
+            let mut cells = window.iter().copied().collect::<Vec<_>>();
+            cells.sort_unstable();
+
+            cells[cells.len() / 2]
+        };
+
+        // The window around (1, 1) is 1 9 2 7 3 6 5 0 5, sorted 0 1 2 3 5 5 6 7 9
+        let clamped = space.map_neighbors(1, BorderMode::Clamped, median);
+
+        assert_eq!(clamped.get(1, 1), Some(&5));
+
+        // Clamping the corner (0, 0) gives 1 1 9 1 1 9 7 7 3, sorted 1 1 1 1 3 7 7 9 9
+        assert_eq!(clamped.get(0, 0), Some(&3));
+
+        let centers = space.map_neighbors(1, BorderMode::Bounded, |window, x, y| (window.width(), window.height(), x, y));
+
+        assert_eq!(centers.get(0, 0), Some(&(2, 2, 0, 0)));
+        assert_eq!(centers.get(2, 1), Some(&(3, 3, 1, 1)));
+        assert_eq!(centers.get(3, 2), Some(&(2, 2, 1, 1)));
+    }
+
+    #[test]
+    fn neighbor_sum_test() {
+        let mut space = Space::new_flat(0i32, 5, 5);
+        space.set(2, 2, 1);
+
+        let orthogonal = space.neighbor_sum(Connectivity::Four, BorderMode::Bounded);
+        let diagonal = space.neighbor_sum(Connectivity::Eight, BorderMode::Bounded);
+
+        for y in 0 .. 5usize {
+            for x in 0 .. 5usize {
+                let (dx, dy) = (x.abs_diff(2), y.abs_diff(2));
+
+                assert_eq!(*orthogonal.get(x, y).unwrap(), (dx + dy == 1) as i32);
+                assert_eq!(*diagonal.get(x, y).unwrap(), (dx.max(dy) == 1) as i32);
+            }
+        }
+
+        // A hot corner is the neighbor of the opposite corner when wrapping
+        let mut corner = Space::new_flat(0i32, 4, 4);
+        corner.set(0, 0, 5);
+
+        assert_eq!(corner.neighbor_sum(Connectivity::Eight, BorderMode::Wrapping).get(3, 3), Some(&5));
+        assert_eq!(corner.neighbor_sum(Connectivity::Eight, BorderMode::Bounded).get(3, 3), Some(&0));
+    }
+
+    #[test]
+    fn map_windowed_average_test() {
+        // 0 1 2 3
+        // 4 5 6 7
+        // 8 9 10 11
+        let space = Space::new_mapped(|x, y| (y * 4 + x) as f32, 4, 3);
+
+        let average = |window: &[&f32]| window.iter().copied().sum::<f32>() / window.len() as f32;
+
+        let clamped = space.map_windowed(3, 3, BorderMode::Clamped, average);
+
+        assert_eq!((clamped.width(), clamped.height()), (4, 3));
+        assert_eq!(clamped.get(1, 1), Some(&5.0));
+
+        // Clamping the corner (0, 0) gives 0 0 1 0 0 1 4 4 5
+        assert_eq!(clamped.get(0, 0), Some(&(15.0 / 9.0)));
+
+        let sizes = space.map_windowed(3, 2, BorderMode::Bounded, |window| window.len());
+
+        assert_eq!(sizes.get(0, 0), Some(&2));
+        assert_eq!(sizes.get(1, 1), Some(&6));
+        assert_eq!(sizes.get(3, 2), Some(&4));
+    }
+}
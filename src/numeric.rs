@@ -0,0 +1,244 @@
+use std::ops::Add;
+
+use crate::Space;
+
+/// The number of independent accumulators used by the chunked sums,
+/// enough for the compiler to fill a vector register with them
+const SUM_LANES: usize = 8;
+
+impl Space<f32> {
+    /// Sums every cell of the space
+    ///
+    /// The backing memory is summed in chunks using several independent accumulators
+    /// so that the loop can be vectorized, this means the additions happen in a different
+    /// order than a sequential fold and the result may differ from one by rounding error
+    #[inline]
+    pub fn sum(&self) -> f32 {
+        let chunks = self.data.chunks_exact(SUM_LANES);
+        let remainder = chunks.remainder();
+
+        let mut lanes = [0.0f32; SUM_LANES];
+
+        for chunk in chunks {
+            for (lane, value) in lanes.iter_mut().zip(chunk) {
+                *lane += *value;
+            }
+        }
+
+        lanes.iter().sum::<f32>() + remainder.iter().sum::<f32>()
+    }
+
+    /// Linearly rescales every cell so that the smallest value becomes 0 and the largest becomes 1
+    /// A space where every cell has the same value becomes all zeros
+    #[inline]
+    pub fn normalize(&self) -> Space<f32> {
+        self.normalize_to(0.0, 1.0)
+    }
+
+    /// Linearly rescales every cell so that the smallest value becomes low and the largest becomes high
+    /// A space where every cell has the same value becomes all low
+    #[inline]
+    pub fn normalize_to(&self, low: f32, high: f32) -> Space<f32> {
+        let min = self.data.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = self.data.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+
+        let range = max - min;
+
+        if range > 0.0 {
+            let scale = (high - low) / range;
+
+            self.convert_with(|value| low + (value - min) * scale)
+        } else {
+            self.convert_with(|_| low)
+        }
+    }
+
+    /// Converts every cell to a byte by rounding it to the nearest integer
+    /// and clamping it into the range 0 to 255, NaN cells become 0
+    #[inline]
+    pub fn to_u8_clamped(&self) -> Space<u8> {
+        self.convert_with(|value| {
+            if value.is_nan() {
+                0
+            } else {
+                value.round().clamp(0.0, 255.0) as u8
+            }
+        })
+    }
+}
+
+impl Space<u8> {
+    /// Creates a new space where every cell is replaced by the entry of the lookup table
+    /// at the index of its value
+    #[inline]
+    pub fn apply_lut<T: Copy>(&self, lut: &[T; 256]) -> Space<T> {
+        self.convert_with(|value| lut[*value as usize])
+    }
+}
+
+impl<T> Space<T> {
+    /// Creates a mask which is true wherever the cell is greater than or equal to the threshold
+    #[inline]
+    pub fn threshold(&self, threshold: T) -> Space<bool>
+        where T: PartialOrd + Copy {
+
+        self.convert_with(|value| *value >= threshold)
+    }
+
+    /// Creates a mask which is true wherever the cell satisfies the predicate
+    #[inline]
+    pub fn threshold_with<F: Fn(&T) -> bool>(&self, pred: F) -> Space<bool> {
+        self.convert_with(pred)
+    }
+
+    /// Adds the weight of every point to the cell at its absolute position,
+    /// so points at the same position accumulate, which builds density maps from point data
+    /// Points outside the space are skipped
+    #[inline]
+    pub fn scatter_add<I: IntoIterator<Item = (usize, usize, T)>>(&mut self, points: I)
+        where T: Add<Output = T> + Copy {
+
+        for (x, y, weight) in points {
+            if let Some(index) = self.index_of(x, y) {
+                self.data[index] = self.data[index] + weight;
+            }
+        }
+    }
+}
+
+macro_rules! impl_integer_sum {
+    ($($int:ty),*) => {
+        $(
+            impl Space<$int> {
+                /// Sums every cell of the space, wrapping on overflow
+                ///
+                /// The backing memory is summed in chunks using several independent accumulators
+                /// so that the loop can be vectorized
+                #[inline]
+                pub fn sum(&self) -> $int {
+                    let chunks = self.data.chunks_exact(SUM_LANES);
+                    let remainder = chunks.remainder();
+
+                    let mut lanes = [0 as $int; SUM_LANES];
+
+                    for chunk in chunks {
+                        for (lane, value) in lanes.iter_mut().zip(chunk) {
+                            *lane = lane.wrapping_add(*value);
+                        }
+                    }
+
+                    lanes.iter()
+                        .chain(remainder)
+                        .fold(0, |acc: $int, value| acc.wrapping_add(*value))
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_sum!(i32, u32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn float_sum_test() {
+        let space = Space::new_mapped(|x, y| (x as f32 * 0.25) - (y as f32 * 0.125), 101, 37);
+
+        let scalar = space.iter().fold(0.0f64, |acc, v| acc + *v as f64);
+
+        assert!((space.sum() as f64 - scalar).abs() < 1e-3);
+    }
+
+    #[test]
+    fn integer_sum_test() {
+        let signed = Space::new_mapped(|x, y| x as i32 - y as i32 * 2, 13, 11);
+        let unsigned = Space::new_mapped(|x, y| (x * y) as u32, 13, 11);
+
+        assert_eq!(signed.sum(), signed.iter().sum::<i32>());
+        assert_eq!(unsigned.sum(), unsigned.iter().sum::<u32>());
+    }
+
+    #[test]
+    fn normalize_test() {
+        let space = Space::new_mapped(|x, y| 10.0 + (y * 5 + x) as f32 * 2.0, 5, 5);
+
+        let normalized = space.normalize();
+
+        assert_eq!(*normalized.get(0, 0).unwrap(), 0.0);
+        assert_eq!(*normalized.get(4, 4).unwrap(), 1.0);
+        assert!((normalized.get(2, 2).unwrap() - 0.5).abs() < 1e-6);
+
+        let ranged = space.normalize_to(-1.0, 1.0);
+
+        assert_eq!(*ranged.get(0, 0).unwrap(), -1.0);
+        assert_eq!(*ranged.get(4, 4).unwrap(), 1.0);
+
+        assert!(Space::new_flat(3.0f32, 4, 4).normalize().iter().all(|v| *v == 0.0));
+    }
+
+    #[test]
+    fn threshold_test() {
+        let gradient = Space::new_mapped(|x, _| x as u8 * 10, 10, 2);
+
+        let mask = gradient.threshold(50);
+
+        assert!(!*mask.get(4, 0).unwrap());
+        assert!(*mask.get(5, 0).unwrap());
+        assert_eq!(mask.iter().filter(|v| **v).count(), 10);
+
+        let band = gradient.threshold_with(|v| (20 .. 40).contains(v));
+
+        assert_eq!(band.iter().filter(|v| **v).count(), 4);
+    }
+
+    #[test]
+    fn apply_lut_test() {
+        let mut inversion = [0u8; 256];
+
+        for (index, entry) in inversion.iter_mut().enumerate() {
+            *entry = 255 - index as u8;
+        }
+
+        let space = Space::new_mapped(|x, y| (y * 16 + x) as u8, 16, 16);
+        let inverted = space.apply_lut(&inversion);
+
+        assert_eq!(*inverted.get(0, 0).unwrap(), 255);
+        assert_eq!(*inverted.get(15, 15).unwrap(), 0);
+        assert!(space.iter().zip(inverted.iter()).all(|(a, b)| *a as u16 + *b as u16 == 255));
+    }
+
+    #[test]
+    fn to_u8_clamped_test() {
+        let values = [-1.0, 0.5, 127.4, 255.9, f32::NAN, f32::INFINITY];
+        let space = Space::from_iter(&mut values.iter().copied(), 3, 2).unwrap();
+
+        let bytes = space.to_u8_clamped();
+
+        assert_eq!(bytes.iter().copied().collect::<Vec<_>>(), vec![0, 1, 127, 255, 0, 255]);
+    }
+
+    #[test]
+    fn convert_with_test() {
+        let space = Space::new_mapped(|x, y| (x, y), 3, 2);
+
+        let sums = space.convert_with(|(x, y)| x + y);
+
+        assert_eq!((sums.width(), sums.height()), (3, 2));
+        assert_eq!(*sums.get(2, 1).unwrap(), 3);
+    }
+
+    #[test]
+    fn scatter_add_test() {
+        let mut heatmap = Space::new_flat(0u32, 4, 3);
+
+        heatmap.scatter_add(vec![(1, 1, 2), (3, 0, 5), (1, 1, 3), (4, 1, 9), (1, 1, 1), (0, 2, 4)]);
+
+        assert_eq!(heatmap.iter().copied().collect::<Vec<_>>(), vec![
+            0, 0, 0, 5,
+            0, 6, 0, 0,
+            4, 0, 0, 0
+        ]);
+    }
+}
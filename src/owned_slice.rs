@@ -0,0 +1,143 @@
+use crate::{Space, SubSpace, SubSpaceMut};
+use crate::primitives::*;
+
+/// An OwnedSlice is a view of a region of a space that owns the space itself,
+/// so it can be passed around and stored without borrowing
+/// The space can be recovered with `into_space`
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct OwnedSlice<T> {
+    /// The space that this OwnedSlice owns
+    space: Space<T>,
+
+    /// The region of the space that this OwnedSlice views
+    region: Region
+}
+
+impl<T> Space<T> {
+    /// Moves this space into an owning view of the entire space
+    #[inline]
+    pub fn into_owned_slice(self) -> OwnedSlice<T> {
+        let region = Region::new(0, 0, self.width, self.height);
+
+        OwnedSlice {
+            space: self,
+            region
+        }
+    }
+}
+
+impl<T> OwnedSlice<T> {
+    /// The width (X direction) of the viewed region
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.region.width
+    }
+
+    /// The height (Y direction) of the viewed region
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.region.height
+    }
+
+    /// The region of the owned space that is viewed, in absolute coordinates
+    #[inline]
+    pub fn region(&self) -> Region {
+        self.region
+    }
+
+    /// Changes the viewed region, which is given in absolute coordinates
+    /// If the region does not fit inside the owned space the view is unchanged and false is returned
+    #[inline]
+    pub fn set_region(&mut self, region: Region) -> bool {
+        if region.clamp_to_size(self.space.width, self.space.height) != region {
+            return false;
+        }
+
+        self.region = region;
+
+        true
+    }
+
+    /// Create a read only slice representing the viewed region
+    #[inline]
+    pub fn as_subspace(&self) -> SubSpace<'_, T> {
+        let Region { x, y, width, height } = self.region;
+
+        self.space.as_subspace_at(x, y, width, height).unwrap()
+    }
+
+    /// Create a mutable slice representing the viewed region
+    #[inline]
+    pub fn as_subspace_mut(&mut self) -> SubSpaceMut<'_, T> {
+        let Region { x, y, width, height } = self.region;
+
+        self.space.as_subspace_mut_at(x, y, width, height).unwrap()
+    }
+
+    /// Creates an immutable reference to a value in the viewed region using
+    /// the specified addressing mode
+    /// If the value queried is outside the region None will be returned
+    #[inline]
+    pub fn get(&self, pos_type: PositioningType, x: usize, y: usize) -> Option<&T> {
+        self.as_subspace().get(pos_type, x, y)
+    }
+
+    /// Sets the value for the specified position in the viewed region using
+    /// the specified addressing mode
+    /// If the position specified is outside the region false is returned
+    #[inline]
+    pub fn set(&mut self, pos_type: PositioningType, x: usize, y: usize, value: T) -> bool {
+        self.as_subspace_mut().set(pos_type, x, y, value)
+    }
+
+    /// Splits the viewed region into two mutable slices horizontally,
+    /// see `SubSpaceMut::split_horizontal`
+    #[inline]
+    pub fn split_horizontal(&mut self, pos_type: PositioningType, x_value: usize) -> HorizontalSplit<SubSpaceMut<'_, T>> {
+        self.as_subspace_mut().split_horizontal(pos_type, x_value)
+    }
+
+    /// Splits the viewed region into two mutable slices vertically,
+    /// see `SubSpaceMut::split_vertical`
+    #[inline]
+    pub fn split_vertical(&mut self, pos_type: PositioningType, y_value: usize) -> VerticalSplit<SubSpaceMut<'_, T>> {
+        self.as_subspace_mut().split_vertical(pos_type, y_value)
+    }
+
+    /// Gives back the owned space, including any cells outside the viewed region
+    #[inline]
+    pub fn into_space(self) -> Space<T> {
+        self.space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn owned_slice_round_trip_test() {
+        let space = Space::new_flat(0u8, 6, 4);
+
+        let mut expected = space.clone();
+        expected.set(2, 1, 7);
+        expected.set(5, 3, 9);
+        expected.set(4, 2, 3);
+
+        let mut owned = space.into_owned_slice();
+
+        assert!(owned.set_region(Region::new(2, 1, 4, 3)));
+        assert!(!owned.set_region(Region::new(3, 1, 4, 3)));
+
+        assert!(owned.set(PositioningType::Relative, 0, 0, 7));
+        assert!(owned.set(PositioningType::Absolute, 5, 3, 9));
+        assert!(!owned.set(PositioningType::Absolute, 1, 1, 9));
+        assert_eq!(owned.get(PositioningType::Relative, 3, 2), Some(&9));
+
+        let HorizontalSplit { mut right, .. } = owned.split_horizontal(PositioningType::Absolute, 4);
+
+        assert!(right.set(PositioningType::Relative, 0, 1, 3));
+
+        assert_eq!(owned.into_space(), expected);
+    }
+}
@@ -0,0 +1,181 @@
+use std::mem::MaybeUninit;
+use std::thread;
+
+use crate::{Space, assume_init_buffer};
+
+/// Computes every cell of a width by height buffer in row-major order,
+/// splitting the rows into contiguous ranges that are each filled by their own thread
+fn fill_rows_parallel<U, F>(width: usize, height: usize, func: &F) -> Box<[U]>
+    where
+        U: Send,
+        F: Fn(usize, usize) -> U + Sync {
+
+    let size = width * height;
+
+    if size == 0 {
+        return Vec::new().into_boxed_slice();
+    }
+
+    let threads = thread::available_parallelism().map_or(1, |n| n.get()).min(height);
+    let rows_per_thread = height.div_ceil(threads);
+
+    let mut buffer: Vec<MaybeUninit<U>> = Vec::with_capacity(size);
+    buffer.resize_with(size, MaybeUninit::uninit);
+
+    thread::scope(|scope| {
+        for (band, chunk) in buffer.chunks_mut(rows_per_thread * width).enumerate() {
+            scope.spawn(move || {
+                let first_row = band * rows_per_thread;
+
+                for (index, cell) in chunk.iter_mut().enumerate() {
+                    cell.write(func(index % width, first_row + index / width));
+                }
+            });
+        }
+    });
+
+    // Every chunk covers a disjoint range of the buffer and each thread wrote every cell
+    // in its chunk before the scope ended, so the whole buffer is initialized
+    unsafe {
+        assume_init_buffer(buffer)
+    }
+}
+
+impl<T> Space<T> {
+    /// Creates a space using the provided function, with the provided dimensions,
+    /// the rows are divided into ranges which are computed on separate threads
+    ///
+    /// The cells are laid out exactly as they are by `new_mapped`
+    /// Only available with the `rayon` feature
+    #[inline]
+    pub fn from_fn_parallel<F>(width: usize, height: usize, func: F) -> Space<T>
+        where
+            T: Send,
+            F: Fn(usize, usize) -> T + Sync {
+
+        Space {
+            data: fill_rows_parallel(width, height, &func),
+            width,
+            height
+        }
+    }
+
+    /// Creates a new space with the same dimensions by applying the function to each cell,
+    /// the rows are divided into ranges which are computed on separate threads
+    ///
+    /// The function is given the absolute position and a reference to the cell
+    /// Only available with the `rayon` feature
+    #[inline]
+    pub fn par_map<U, F>(&self, func: F) -> Space<U>
+        where
+            T: Sync,
+            U: Send,
+            F: Fn(usize, usize, &T) -> U + Sync {
+
+        let width = self.width;
+        let data = &self.data;
+
+        Space {
+            data: fill_rows_parallel(self.width, self.height, &|x, y| func(x, y, &data[y * width + x])),
+            width: self.width,
+            height: self.height
+        }
+    }
+
+    /// Reduces every cell of the space to a single value, the rows are divided into ranges
+    /// which are each reduced on a separate thread before the partial results are combined
+    ///
+    /// Each cell is mapped and combined into a range's result starting from the identity,
+    /// then the results of the ranges are combined from top to bottom
+    /// The combine function must be associative, and the identity must leave values
+    /// unchanged when combined with them, for the result to match a sequential fold
+    /// Only available with the `rayon` feature
+    #[inline]
+    pub fn par_reduce<B, F, G>(&self, identity: B, map: F, combine: G) -> B
+        where
+            T: Sync,
+            B: Send + Clone,
+            F: Fn(&T) -> B + Sync,
+            G: Fn(B, B) -> B + Sync {
+
+        if self.data.is_empty() {
+            return identity;
+        }
+
+        let threads = thread::available_parallelism().map_or(1, |n| n.get()).min(self.height);
+        let rows_per_thread = self.height.div_ceil(threads);
+
+        let (map, combine) = (&map, &combine);
+
+        let partials = thread::scope(|scope| {
+            let handles = self.data.chunks(rows_per_thread * self.width)
+                .map(|chunk| {
+                    let identity = identity.clone();
+
+                    scope.spawn(move || chunk.iter().fold(identity, |acc, value| combine(acc, map(value))))
+                })
+                .collect::<Vec<_>>();
+
+            handles.into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        partials.into_iter().fold(identity, combine)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expensive(x: usize, y: usize, value: &u64) -> u64 {
+        (0 .. 1000u64).fold(*value, |acc, i| acc.wrapping_mul(31).wrapping_add(i ^ (x * y) as u64))
+    }
+
+    #[test]
+    fn par_map_test() {
+        let space = Space::new_mapped(|x, y| (x * 7 + y) as u64, 37, 53);
+
+        let parallel = space.par_map(expensive);
+        let sequential = Space::from_iter(
+            &mut space.iter().enumerate().map(|(i, v)| expensive(i % 37, i / 37, v)),
+            37,
+            53
+        ).unwrap();
+
+        assert_eq!(parallel, sequential);
+    }
+
+    #[test]
+    fn from_fn_parallel_test() {
+        fn generate(x: usize, y: usize) -> (usize, usize, u64) {
+            (x, y, expensive(x, y, &((x + y) as u64)))
+        }
+
+        let parallel = Space::from_fn_parallel(61, 29, generate);
+        let sequential = Space::new_mapped(generate, 61, 29);
+
+        assert_eq!(parallel, sequential);
+    }
+
+    #[test]
+    fn par_map_empty_test() {
+        let space = Space::new_flat(0u8, 0, 5);
+
+        assert_eq!(space.par_map(|_, _, v| *v).width(), 0);
+    }
+
+    #[test]
+    fn par_reduce_test() {
+        let space = Space::new_mapped(|x, y| ((x * 131 + y * 71) % 1000) as u64, 83, 47);
+
+        let sum = space.par_reduce(0, |v| *v, |a, b| a + b);
+        let max = space.par_reduce(0, |v| *v, u64::max);
+
+        assert_eq!(sum, space.iter().sum::<u64>());
+        assert_eq!(max, *space.iter().max().unwrap());
+
+        assert_eq!(Space::new_flat(1u64, 4, 0).par_reduce(0, |v| *v, |a, b| a + b), 0);
+    }
+}
@@ -1,6 +1,6 @@
 
 /// A positioning type indicates how to interpret an X/Y coordinate in a slice
-pub enum PostioningType {
+pub enum PositioningType {
     /// Absolute positioning indexes directly into the space that this slice references
     Absolute, 
 
@@ -10,6 +10,16 @@ pub enum PostioningType {
     Relative
 }
 
+/// The original, misspelled name of `PositioningType`
+///
+/// ```compile_fail
+/// #![deny(deprecated)]
+///
+/// let _ = spatial_slice::PostioningType::Absolute;
+/// ```
+#[deprecated(note = "renamed to `PositioningType`")]
+pub type PostioningType = PositioningType;
+
 /// Represents a partition with left and right values
 pub struct HorizontalSplit<T> {
     pub left: T,
@@ -20,4 +30,352 @@ pub struct HorizontalSplit<T> {
 pub struct VerticalSplit<T> {
     pub above: T,
     pub below: T
-}
\ No newline at end of file
+}
+
+/// Represents a partition into four values around a single point
+pub struct Quadrants<T> {
+    pub top_left: T,
+    pub top_right: T,
+    pub bottom_left: T,
+    pub bottom_right: T
+}
+
+/// A border mode indicates how to treat samples that fall outside a space,
+/// such as those taken by a kernel centered near an edge
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum BorderMode {
+    /// Out of range samples are ignored
+    Bounded,
+
+    /// Out of range samples are moved to the nearest edge cell
+    Clamped,
+
+    /// Out of range samples wrap around to the opposite edge,
+    /// treating the space as a torus
+    Wrapping
+}
+
+impl BorderMode {
+    /// Resolves a possibly out of range coordinate into a space with the given dimensions
+    /// If the coordinate has no corresponding cell None is returned
+    #[inline]
+    pub(crate) fn resolve(self, x: isize, y: isize, width: usize, height: usize) -> Option<(usize, usize)> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        let (w, h) = (width as isize, height as isize);
+
+        match self {
+            BorderMode::Bounded => {
+                if x < 0 || y < 0 || x >= w || y >= h {
+                    None
+                } else {
+                    Some((x as usize, y as usize))
+                }
+            }
+            BorderMode::Clamped => {
+                Some((x.max(0).min(w - 1) as usize, y.max(0).min(h - 1) as usize))
+            }
+            BorderMode::Wrapping => {
+                Some((x.rem_euclid(w) as usize, y.rem_euclid(h) as usize))
+            }
+        }
+    }
+}
+
+/// A connectivity indicates which cells count as the neighbors of a cell
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Connectivity {
+    /// The four orthogonally adjacent cells (von Neumann neighborhood)
+    Four,
+
+    /// The four orthogonally and four diagonally adjacent cells (Moore neighborhood)
+    Eight
+}
+
+impl Connectivity {
+    /// The (dx, dy) offsets from a cell to each of its neighbors
+    #[inline]
+    pub(crate) fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Connectivity::Four => &[
+                (0, -1),
+                (-1, 0), (1, 0),
+                (0, 1)
+            ],
+            Connectivity::Eight => &[
+                (-1, -1), (0, -1), (1, -1),
+                (-1, 0),           (1, 0),
+                (-1, 1),  (0, 1),  (1, 1)
+            ]
+        }
+    }
+}
+
+/// A clockwise rotation by a multiple of 90 degrees
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Rotation {
+    /// No rotation
+    None,
+
+    /// A quarter turn clockwise
+    Cw90,
+
+    /// A half turn
+    Cw180,
+
+    /// Three quarter turns clockwise, or a quarter turn counterclockwise
+    Cw270
+}
+
+/// The point of a space that stays fixed when it is fitted into a different size,
+/// the cells at this point line up with the same point of the result
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Anchor {
+    /// The top left corner
+    TopLeft,
+
+    /// The middle of the top edge
+    Top,
+
+    /// The top right corner
+    TopRight,
+
+    /// The middle of the left edge
+    Left,
+
+    /// The center, when a dimension changes by an odd amount the extra cell
+    /// is cropped from or padded onto the right or bottom
+    Center,
+
+    /// The middle of the right edge
+    Right,
+
+    /// The bottom left corner
+    BottomLeft,
+
+    /// The middle of the bottom edge
+    Bottom,
+
+    /// The bottom right corner
+    BottomRight
+}
+
+impl Anchor {
+    /// The offset of a space with the old dimensions when placed in the new dimensions,
+    /// negative when the old dimension is larger and has to be cropped
+    #[inline]
+    pub(crate) fn offset(self, old_width: usize, old_height: usize, new_width: usize, new_height: usize) -> (isize, isize) {
+        // How far along each axis the anchor lies, in halves
+        let (halves_x, halves_y) = match self {
+            Anchor::TopLeft => (0, 0),
+            Anchor::Top => (1, 0),
+            Anchor::TopRight => (2, 0),
+            Anchor::Left => (0, 1),
+            Anchor::Center => (1, 1),
+            Anchor::Right => (2, 1),
+            Anchor::BottomLeft => (0, 2),
+            Anchor::Bottom => (1, 2),
+            Anchor::BottomRight => (2, 2)
+        };
+
+        (
+            (new_width as isize - old_width as isize) * halves_x / 2,
+            (new_height as isize - old_height as isize) * halves_y / 2
+        )
+    }
+}
+
+/// One of the eight orientations an image can be stored in, as described by the EXIF orientation tag,
+/// each variant names the transform that brings the stored image upright
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Orientation {
+    /// EXIF orientation 1, the image is already upright
+    Identity,
+
+    /// EXIF orientation 2, mirrored left to right
+    MirrorHorizontal,
+
+    /// EXIF orientation 3, a half turn
+    Rotate180,
+
+    /// EXIF orientation 4, mirrored top to bottom
+    MirrorVertical,
+
+    /// EXIF orientation 5, reflected across the main diagonal,
+    /// which is a left to right mirror followed by a quarter turn counterclockwise
+    Transpose,
+
+    /// EXIF orientation 6, a quarter turn clockwise
+    Rotate90,
+
+    /// EXIF orientation 7, reflected across the anti-diagonal,
+    /// which is a left to right mirror followed by a quarter turn clockwise
+    Transverse,
+
+    /// EXIF orientation 8, three quarter turns clockwise
+    Rotate270
+}
+
+impl Orientation {
+    /// Converts the value of an EXIF orientation tag into an orientation
+    /// If the value is not between 1 and 8 None is returned
+    #[inline]
+    pub fn from_exif(value: u8) -> Option<Orientation> {
+        match value {
+            1 => Some(Orientation::Identity),
+            2 => Some(Orientation::MirrorHorizontal),
+            3 => Some(Orientation::Rotate180),
+            4 => Some(Orientation::MirrorVertical),
+            5 => Some(Orientation::Transpose),
+            6 => Some(Orientation::Rotate90),
+            7 => Some(Orientation::Transverse),
+            8 => Some(Orientation::Rotate270),
+            _ => None
+        }
+    }
+
+    /// Whether this orientation swaps the width and height
+    #[inline]
+    pub fn swaps_dimensions(self) -> bool {
+        matches!(self, Orientation::Transpose | Orientation::Rotate90 | Orientation::Transverse | Orientation::Rotate270)
+    }
+}
+
+/// A rectangular area of a space, described by its top left corner and its dimensions
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct Region {
+    /// The X position that this Region starts at
+    pub x: usize,
+
+    /// The Y position that this Region starts at
+    pub y: usize,
+
+    /// The width (X direction) of this Region
+    pub width: usize,
+
+    /// The height (Y direction) of this Region
+    pub height: usize
+}
+
+impl Region {
+    /// Creates a region with the provided position and dimensions
+    #[inline]
+    pub fn new(x: usize, y: usize, width: usize, height: usize) -> Self {
+        Region {
+            x,
+            y,
+            width,
+            height
+        }
+    }
+
+    /// Checks whether the provided position lies inside this region
+    #[inline]
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x >= self.x
+            && y >= self.y
+            && x - self.x < self.width
+            && y - self.y < self.height
+    }
+
+    /// Checks whether every cell of this region lies inside the bounds
+    #[inline]
+    pub fn is_within(&self, bounds: Region) -> bool {
+        self.clamp_to(bounds) == *self
+    }
+
+    /// Checks whether this region and the other share at least one cell,
+    /// a region without cells never overlaps anything
+    #[inline]
+    pub fn overlaps(&self, other: Region) -> bool {
+        let shared = self.clamp_to(other);
+
+        shared.width > 0 && shared.height > 0
+    }
+
+    /// Shrinks this region to the part of it that lies inside the bounds
+    /// If the regions do not overlap the result has no cells and lies on the edge of the bounds
+    #[inline]
+    pub fn clamp_to(&self, bounds: Region) -> Region {
+        let right = bounds.x.saturating_add(bounds.width);
+        let bottom = bounds.y.saturating_add(bounds.height);
+
+        let x = self.x.clamp(bounds.x, right);
+        let y = self.y.clamp(bounds.y, bottom);
+
+        Region {
+            x,
+            y,
+            width: self.x.saturating_add(self.width).clamp(x, right) - x,
+            height: self.y.saturating_add(self.height).clamp(y, bottom) - y
+        }
+    }
+
+    /// Shrinks this region so that it fits inside a space with the provided dimensions
+    #[inline]
+    pub(crate) fn clamp_to_size(&self, width: usize, height: usize) -> Region {
+        let x = self.x.min(width);
+        let y = self.y.min(height);
+
+        Region {
+            x,
+            y,
+            width: self.width.min(width - x),
+            height: self.height.min(height - y)
+        }
+    }
+}
+
+/// A metric measures the distance between two cells of a space
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Metric {
+    /// The sum of the X and Y distances, the number of orthogonal steps between two cells
+    Manhattan,
+
+    /// The larger of the X and Y distances, the number of orthogonal or diagonal steps between two cells
+    Chebyshev,
+
+    /// The straight line distance between two cells
+    Euclidean
+}
+
+impl Metric {
+    /// A value that orders offsets in the same way as this metric,
+    /// for Euclidean this is the squared distance so that no rounding is involved
+    #[inline]
+    pub(crate) fn rank(self, dx: usize, dy: usize) -> usize {
+        match self {
+            Metric::Manhattan => dx + dy,
+            Metric::Chebyshev => dx.max(dy),
+            Metric::Euclidean => dx * dx + dy * dy
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Space;
+    use super::*;
+
+    #[test]
+    fn positioning_type_test() {
+        let space = Space::new_mapped(|x, y| (x, y), 4, 4);
+        let subspace = space.as_subspace_at(1, 1, 2, 2).unwrap();
+
+        assert_eq!(subspace.get(PositioningType::Relative, 0, 0), Some(&(1, 1)));
+        assert_eq!(subspace.get(PositioningType::Absolute, 0, 0), None);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_positioning_type_test() {
+        let space = Space::new_mapped(|x, y| (x, y), 4, 4);
+        let subspace = space.as_subspace_at(1, 1, 2, 2).unwrap();
+
+        let position: PostioningType = PostioningType::Absolute;
+
+        assert_eq!(subspace.get(position, 2, 2), Some(&(2, 2)));
+    }
+}
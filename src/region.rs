@@ -0,0 +1,680 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use crate::Space;
+use crate::primitives::*;
+
+/// Checks whether a position lies within the given thickness of the edge
+/// of an area with the provided dimensions
+#[inline]
+pub(crate) fn in_border(x: usize, y: usize, width: usize, height: usize, thickness: usize) -> bool {
+    x < thickness
+        || y < thickness
+        || x + thickness >= width
+        || y + thickness >= height
+}
+
+impl<T> Space<T> {
+    /// The region covering the entire space
+    #[inline]
+    pub fn bounds(&self) -> Region {
+        Region::new(0, 0, self.width, self.height)
+    }
+
+    /// Creates a new space containing a clone of every cell in the region,
+    /// which must fit inside the space
+    #[inline]
+    pub(crate) fn clone_region(&self, region: Region) -> Space<T>
+        where T: Clone {
+
+        let mut vec = Vec::with_capacity(region.width * region.height);
+
+        for y in region.y .. region.y + region.height {
+            let start = y * self.width + region.x;
+
+            vec.extend_from_slice(&self.data[start .. start + region.width]);
+        }
+
+        Space {
+            data: vec.into_boxed_slice(),
+            width: region.width,
+            height: region.height
+        }
+    }
+
+    /// Moves every cell in the region out into a new space, leaving the default value in its place,
+    /// which cuts a region without requiring the cells to be Clone
+    /// Any part of the region outside the space is ignored
+    #[inline]
+    pub fn take_region(&mut self, region: Region) -> Space<T>
+        where T: Default {
+
+        let region = region.clamp_to_size(self.width, self.height);
+
+        let mut vec = Vec::with_capacity(region.width * region.height);
+
+        for y in region.y .. region.y + region.height {
+            let start = y * self.width + region.x;
+
+            vec.extend(self.data[start .. start + region.width].iter_mut().map(std::mem::take));
+        }
+
+        Space {
+            data: vec.into_boxed_slice(),
+            width: region.width,
+            height: region.height
+        }
+    }
+
+    /// Finds the smallest region that contains every cell satisfying the predicate
+    /// If no cell satisfies the predicate None is returned
+    #[inline]
+    pub fn bounding_box<F: Fn(&T) -> bool>(&self, pred: F) -> Option<Region> {
+        let mut bounds: Option<(usize, usize, usize, usize)> = None;
+
+        for (index, value) in self.data.iter().enumerate() {
+            if !pred(value) {
+                continue;
+            }
+
+            let (x, y) = (index % self.width, index / self.width);
+
+            bounds = Some(match bounds {
+                Some((min_x, min_y, max_x, max_y)) => (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y)),
+                None => (x, y, x, y)
+            });
+        }
+
+        bounds.map(|(min_x, min_y, max_x, max_y)| Region::new(min_x, min_y, max_x - min_x + 1, max_y - min_y + 1))
+    }
+
+    /// Creates a new space with the same dimensions that is true exactly where the cell
+    /// differs from the cell at the same position in the other space,
+    /// the `bounding_box` of the true cells is then the dirty rectangle between two frames
+    /// If the dimensions differ None is returned
+    #[inline]
+    pub fn difference_mask(&self, other: &Space<T>) -> Option<Space<bool>>
+        where T: PartialEq {
+
+        if !self.same_shape(other) {
+            return None;
+        }
+
+        Some(Space {
+            data: self.data.iter().zip(other.data.iter()).map(|(a, b)| a != b).collect::<Vec<_>>().into_boxed_slice(),
+            width: self.width,
+            height: self.height
+        })
+    }
+
+    /// Creates a new space cropped to the smallest region containing every cell
+    /// that is not background
+    /// If every cell is background None is returned
+    #[inline]
+    pub fn trim<F: Fn(&T) -> bool>(&self, is_background: F) -> Option<Space<T>>
+        where T: Clone {
+
+        let region = self.bounding_box(|value| !is_background(value))?;
+
+        Some(self.clone_region(region))
+    }
+
+    /// Creates a new space without the rows and columns around the edges that are entirely margin,
+    /// along with the region of this space that was kept
+    /// If every cell is margin None is returned
+    #[inline]
+    pub fn trim_margins<F: Fn(&T) -> bool>(&self, is_margin: F) -> Option<(Space<T>, Region)>
+        where T: Clone {
+
+        let region = self.bounding_box(|value| !is_margin(value))?;
+
+        Some((self.clone_region(region), region))
+    }
+
+    /// Creates an iterator over the absolute position and value of every cell in the region,
+    /// reading it lexicographically
+    /// Any part of the region outside the space is ignored
+    #[inline]
+    pub fn iter_region(&self, region: Region) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        let region = region.clamp_to_size(self.width, self.height);
+        let width = self.width;
+
+        (region.y .. region.y + region.height).flat_map(move |y| {
+            let start = y * width + region.x;
+
+            self.data[start .. start + region.width].iter()
+                .enumerate()
+                .map(move |(offset, value)| (region.x + offset, y, value))
+        })
+    }
+
+    /// Computes a hash of the cells in the region, read lexicographically, and of its dimensions,
+    /// so identical regions hash equally and regions of different shapes differ even when their cells match
+    /// Any part of the region outside the space is ignored
+    ///
+    /// The hash is only stable within a single build, so it suits change detection but should not be stored
+    #[inline]
+    pub fn hash_region(&self, region: Region) -> u64
+        where T: Hash {
+
+        let region = region.clamp_to_size(self.width, self.height);
+        let mut hasher = DefaultHasher::new();
+
+        region.width.hash(&mut hasher);
+        region.height.hash(&mut hasher);
+
+        for (_, _, value) in self.iter_region(region) {
+            value.hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
+
+    /// Combines every cell in the region into an accumulator, reading it lexicographically
+    /// The function is given the accumulator, the absolute position and the value of each cell
+    /// Any part of the region outside the space is ignored
+    #[inline]
+    pub fn fold_region<B, F: FnMut(B, usize, usize, &T) -> B>(&self, region: Region, init: B, mut func: F) -> B {
+        self.iter_region(region).fold(init, |acc, (x, y, value)| func(acc, x, y, value))
+    }
+
+    /// Sets the value of every cell in the region based on its absolute position,
+    /// visiting the cells lexicographically
+    /// Any part of the region outside the space is ignored
+    #[inline]
+    pub fn fill_region_with<F: FnMut(usize, usize) -> T>(&mut self, region: Region, mut func: F) {
+        let region = region.clamp_to_size(self.width, self.height);
+
+        for y in region.y .. region.y + region.height {
+            for x in region.x .. region.x + region.width {
+                self.data[y * self.width + x] = func(x, y);
+            }
+        }
+    }
+
+    /// Updates every cell in the region in place based on its absolute position and current value,
+    /// visiting the cells lexicographically
+    /// Any part of the region outside the space is ignored
+    #[inline]
+    pub fn map_region_mut<F: FnMut(usize, usize, &mut T)>(&mut self, region: Region, mut func: F) {
+        let region = region.clamp_to_size(self.width, self.height);
+
+        for y in region.y .. region.y + region.height {
+            let start = y * self.width + region.x;
+
+            for (offset, value) in self.data[start .. start + region.width].iter_mut().enumerate() {
+                func(region.x + offset, y, value);
+            }
+        }
+    }
+
+    /// Copies the cells of the source region so that its top left corner lands at the destination
+    /// The source region may overlap the destination, the cells are copied in an order
+    /// that reads every source cell before it is overwritten
+    /// Any part of the source region outside the space, or that would be copied outside the space, is ignored
+    #[inline]
+    pub fn copy_within(&mut self, src: Region, dst_x: usize, dst_y: usize)
+        where T: Clone {
+
+        let src = src.clamp_to_size(self.width, self.height);
+
+        let width = src.width.min(self.width.saturating_sub(dst_x));
+        let height = src.height.min(self.height.saturating_sub(dst_y));
+
+        // Walk away from the destination so that overlapping source cells are read first
+        for row in 0 .. height {
+            let row = if dst_y > src.y { height - 1 - row } else { row };
+
+            let src_start = (src.y + row) * self.width + src.x;
+            let dst_start = (dst_y + row) * self.width + dst_x;
+
+            for column in 0 .. width {
+                let column = if dst_x > src.x { width - 1 - column } else { column };
+
+                self.data[dst_start + column] = self.data[src_start + column].clone();
+            }
+        }
+    }
+
+    /// Sets every cell within the thickness of the edge of the space to the value,
+    /// leaving the interior untouched
+    /// A thickness of at least half of a dimension fills the whole space
+    #[inline]
+    pub fn fill_border(&mut self, thickness: usize, value: T)
+        where T: Clone {
+
+        let (width, height) = (self.width, self.height);
+
+        for y in 0 .. height {
+            for x in 0 .. width {
+                if in_border(x, y, width, height, thickness) {
+                    self.data[y * width + x] = value.clone();
+                }
+            }
+        }
+    }
+
+    /// Creates a new space with the provided dimensions holding this space placed at the anchor,
+    /// dimensions that shrink crop the cells that no longer fit and dimensions that grow
+    /// are padded with the fill value
+    #[inline]
+    pub fn fit_to(&self, new_width: usize, new_height: usize, fill: T, anchor: Anchor) -> Space<T>
+        where T: Clone {
+
+        let (offset_x, offset_y) = anchor.offset(self.width, self.height, new_width, new_height);
+
+        let mut vec = Vec::with_capacity(new_width * new_height);
+
+        for y in 0 .. new_height as isize {
+            for x in 0 .. new_width as isize {
+                let (source_x, source_y) = (x - offset_x, y - offset_y);
+
+                let inside = source_x >= 0
+                    && source_y >= 0
+                    && (source_x as usize) < self.width
+                    && (source_y as usize) < self.height;
+
+                if inside {
+                    vec.push(self.data[source_y as usize * self.width + source_x as usize].clone());
+                } else {
+                    vec.push(fill.clone());
+                }
+            }
+        }
+
+        Space {
+            data: vec.into_boxed_slice(),
+            width: new_width,
+            height: new_height
+        }
+    }
+
+    /// Changes the dimensions of this space in place, keeping the cells of the top left rectangle
+    /// that fits in both sizes, which is a simpler `fit_to` that reuses the cells instead of cloning them
+    /// Cells that no longer fit are dropped and new cells are set to the fill value
+    #[inline]
+    pub fn resize(&mut self, new_width: usize, new_height: usize, fill: T)
+        where T: Clone {
+
+        let mut cells = std::mem::take(&mut self.data).into_vec().into_iter();
+        let mut vec = Vec::with_capacity(new_width * new_height);
+
+        for y in 0 .. new_height.min(self.height) {
+            for (x, value) in cells.by_ref().take(self.width).enumerate() {
+                if x < new_width {
+                    vec.push(value);
+                }
+            }
+
+            vec.resize((y + 1) * new_width, fill.clone());
+        }
+
+        vec.resize(new_width * new_height, fill);
+
+        self.data = vec.into_boxed_slice();
+        self.width = new_width;
+        self.height = new_height;
+    }
+
+    /// Creates a new space whose width and height are rounded up to the next power of two,
+    /// with this space in the top left corner and the cells around it set to the fill value
+    /// A dimension of zero becomes one, as that is the smallest power of two
+    #[inline]
+    pub fn pad_to_pow2(&self, fill: T) -> Space<T>
+        where T: Clone {
+
+        self.fit_to(self.width.next_power_of_two(), self.height.next_power_of_two(), fill, Anchor::TopLeft)
+    }
+
+    /// Overwrites every cell outside the region with the fill value,
+    /// leaving the cells inside the region untouched
+    /// Any part of the region outside the space is ignored
+    #[inline]
+    pub fn mask_outside(&mut self, region: Region, fill: T)
+        where T: Clone {
+
+        let region = region.clamp_to_size(self.width, self.height);
+
+        for y in 0 .. self.height {
+            for x in 0 .. self.width {
+                if !region.contains(x, y) {
+                    self.data[y * self.width + x] = fill.clone();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fit_to_test() {
+        //  0  1  2  3
+        //  4  5  6  7
+        //  8  9 10 11
+        // 12 13 14 15
+        let space = Space::new_mapped(|x, y| y * 4 + x, 4, 4);
+
+        let centered = space.fit_to(2, 6, 99, Anchor::Center);
+
+        assert_eq!((centered.width(), centered.height()), (2, 6));
+        assert_eq!(centered.iter().copied().collect::<Vec<_>>(), vec![99, 99, 1, 2, 5, 6, 9, 10, 13, 14, 99, 99]);
+
+        let corner = space.fit_to(5, 3, 99, Anchor::BottomRight);
+
+        assert_eq!(corner.iter().copied().collect::<Vec<_>>(), vec![99, 4, 5, 6, 7, 99, 8, 9, 10, 11, 99, 12, 13, 14, 15]);
+        assert_eq!(space.fit_to(4, 4, 99, Anchor::TopLeft), space);
+    }
+
+    #[test]
+    fn clamp_to_bounds_test() {
+        let space = Space::new_flat(0u8, 8, 6);
+
+        assert_eq!(space.bounds(), Region::new(0, 0, 8, 6));
+
+        let overhanging = Region::new(5, 4, 10, 10).clamp_to(space.bounds());
+
+        assert_eq!(overhanging, Region::new(5, 4, 3, 2));
+
+        let inner = Region::new(2, 2, 4, 4);
+
+        assert_eq!(Region::new(0, 3, 3, 2).clamp_to(inner), Region::new(2, 3, 1, 2));
+        assert_eq!(inner.clamp_to(space.bounds()), inner);
+        assert!(inner.is_within(space.bounds()));
+        assert!(!Region::new(5, 4, 10, 10).is_within(space.bounds()));
+        assert_eq!(Region::new(7, 0, 2, 2).clamp_to(inner), Region::new(6, 2, 0, 0));
+    }
+
+    #[test]
+    fn pad_to_pow2_test() {
+        let space = Space::new_mapped(|x, y| y * 3 + x + 1, 3, 5);
+
+        let padded = space.pad_to_pow2(0);
+
+        assert_eq!((padded.width(), padded.height()), (4, 8));
+
+        for y in 0 .. 8 {
+            for x in 0 .. 4 {
+                let expected = if x < 3 && y < 5 { y * 3 + x + 1 } else { 0 };
+
+                assert_eq!(*padded.get(x, y).unwrap(), expected);
+            }
+        }
+
+        assert_eq!(padded.pad_to_pow2(9), padded);
+    }
+
+    #[test]
+    fn mask_outside_test() {
+        let mut space = Space::new_flat(1u8, 4, 4);
+
+        space.mask_outside(Region::new(1, 1, 2, 2), 0);
+
+        for y in 0 .. 4 {
+            for x in 0 .. 4 {
+                let inside = (1 ..= 2).contains(&x) && (1 ..= 2).contains(&y);
+
+                assert_eq!(*space.get(x, y).unwrap(), inside as u8);
+            }
+        }
+    }
+
+    #[test]
+    fn mask_outside_overhanging_test() {
+        let mut space = Space::new_flat(1u8, 4, 4);
+
+        space.mask_outside(Region::new(2, 3, 10, 10), 0);
+
+        assert_eq!(space.iter().filter(|v| **v == 1).count(), 2);
+        assert_eq!(*space.get(3, 3).unwrap(), 1);
+    }
+
+    #[test]
+    fn fill_border_test() {
+        let mut space = Space::new_mapped(|x, y| (y * 5 + x) as u8, 5, 5);
+
+        space.fill_border(1, 100);
+
+        for y in 0 .. 5 {
+            for x in 0 .. 5 {
+                let expected = if x == 0 || y == 0 || x == 4 || y == 4 { 100 } else { (y * 5 + x) as u8 };
+
+                assert_eq!(*space.get(x, y).unwrap(), expected);
+            }
+        }
+
+        space.fill_border(3, 7);
+
+        assert!(space.iter().all(|v| *v == 7));
+    }
+
+    #[test]
+    fn trim_test() {
+        let mut space = Space::new_flat(0u8, 8, 6);
+
+        space.set(2, 1, 1);
+        space.set(4, 3, 2);
+        space.set(3, 2, 3);
+
+        assert_eq!(space.bounding_box(|v| *v != 0), Some(Region::new(2, 1, 3, 3)));
+
+        let trimmed = space.trim(|v| *v == 0).unwrap();
+
+        assert_eq!((trimmed.width(), trimmed.height()), (3, 3));
+        assert_eq!(trimmed.iter().copied().collect::<Vec<_>>(), vec![1, 0, 0, 0, 3, 0, 0, 0, 2]);
+
+        assert_eq!(Space::new_flat(0u8, 4, 4).trim(|v| *v == 0), None);
+    }
+
+    #[test]
+    fn trim_margins_test() {
+        let mut space = Space::new_flat(0u8, 7, 5);
+
+        space.fill_region_with(Region::new(1, 2, 4, 2), |x, y| (x + y) as u8);
+
+        let (trimmed, kept) = space.trim_margins(|v| *v == 0).unwrap();
+
+        assert_eq!((trimmed.width(), trimmed.height()), (4, 2));
+        assert_eq!(kept, Region::new(1, 2, 4, 2));
+        assert_eq!(trimmed.get(0, 0), space.get(kept.x, kept.y));
+
+        assert_eq!(Space::new_flat(0u8, 3, 3).trim_margins(|v| *v == 0), None);
+    }
+
+    #[test]
+    fn take_region_test() {
+        let mut space = Space::new_flat(String::from("a"), 5, 4);
+
+        space.fill_region_with(Region::new(1, 1, 3, 2), |x, y| format!("{},{}", x, y));
+
+        let cut = space.take_region(Region::new(1, 1, 3, 2));
+
+        assert_eq!((cut.width(), cut.height()), (3, 2));
+        assert_eq!(cut.get(0, 0).unwrap(), "1,1");
+        assert_eq!(cut.get(2, 1).unwrap(), "3,2");
+
+        for (x, y, value) in space.iter_region(space.bounds()) {
+            let expected = if Region::new(1, 1, 3, 2).contains(x, y) { "" } else { "a" };
+
+            assert_eq!(value, expected);
+        }
+
+        let overhanging = space.take_region(Region::new(4, 3, 5, 5));
+
+        assert_eq!((overhanging.width(), overhanging.height()), (1, 1));
+    }
+
+    #[test]
+    fn hash_region_test() {
+        let mut space = Space::new_mapped(|x, y| (x * y) as u8, 6, 6);
+        let region = Region::new(1, 1, 3, 3);
+
+        let before = space.hash_region(region);
+
+        space.set(5, 5, 99);
+
+        assert_eq!(space.hash_region(region), before);
+
+        space.set(2, 3, 99);
+
+        assert_ne!(space.hash_region(region), before);
+
+        // The same cells in different shapes hash differently
+        let flat = Space::new_flat(0u8, 6, 6);
+
+        assert_ne!(flat.hash_region(Region::new(0, 0, 4, 1)), flat.hash_region(Region::new(0, 0, 2, 2)));
+        assert_eq!(flat.hash_region(Region::new(0, 0, 2, 2)), flat.hash_region(Region::new(3, 3, 2, 2)));
+    }
+
+    #[test]
+    fn iter_region_test() {
+        let space = Space::new_mapped(|x, y| y * 4 + x, 4, 4);
+
+        let cells = space.iter_region(Region::new(1, 1, 2, 2)).collect::<Vec<_>>();
+
+        assert_eq!(cells, vec![(1, 1, &5), (2, 1, &6), (1, 2, &9), (2, 2, &10)]);
+        assert_eq!(space.iter_region(Region::new(3, 3, 5, 5)).count(), 1);
+        assert_eq!(space.iter_region(Region::new(4, 0, 1, 1)).count(), 0);
+    }
+
+    #[test]
+    fn copy_within_overlap_test() {
+        // 0 1 2 3
+        // 4 5 6 7
+        let mut space = Space::new_mapped(|x, y| y * 4 + x, 4, 2);
+
+        space.copy_within(Region::new(0, 0, 4, 2), 1, 0);
+
+        assert_eq!(space.iter().copied().collect::<Vec<_>>(), vec![0, 0, 1, 2, 4, 4, 5, 6]);
+
+        space.copy_within(Region::new(1, 0, 3, 2), 0, 0);
+
+        assert_eq!(space.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 2, 4, 5, 6, 6]);
+    }
+
+    #[test]
+    fn copy_within_vertical_test() {
+        let mut space = Space::new_mapped(|x, y| y * 3 + x, 3, 4);
+
+        space.copy_within(Region::new(0, 0, 3, 3), 0, 1);
+
+        assert_eq!(space.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 0, 1, 2, 3, 4, 5, 6, 7, 8]);
+
+        space.copy_within(Region::new(0, 0, 1, 1), 5, 5);
+
+        assert_eq!(*space.get(0, 3).unwrap(), 6);
+    }
+
+    #[test]
+    fn fold_region_test() {
+        let space = Space::new_mapped(|x, y| (y * 6 + x) as u32, 6, 5);
+
+        let sum = space.fold_region(Region::new(1, 1, 4, 3), 0, |acc, _, _, v| acc + v);
+
+        let mut expected = 0;
+
+        for y in 1 .. 4 {
+            for x in 1 .. 5 {
+                expected += space.get(x, y).unwrap();
+            }
+        }
+
+        assert_eq!(sum, expected);
+
+        let corners = space.fold_region(Region::new(4, 3, 9, 9), Vec::new(), |mut acc, x, y, _| {
+            acc.push((x, y));
+            acc
+        });
+
+        assert_eq!(corners, vec![(4, 3), (5, 3), (4, 4), (5, 4)]);
+    }
+
+    #[test]
+    fn fill_region_with_test() {
+        let mut space = Space::new_flat(0usize, 6, 6);
+        let region = Region::new(2, 1, 3, 4);
+
+        space.fill_region_with(region, |x, y| x * 10 + y);
+
+        for y in 0 .. 6 {
+            for x in 0 .. 6 {
+                let expected = if region.contains(x, y) { x * 10 + y } else { 0 };
+
+                assert_eq!(*space.get(x, y).unwrap(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn map_region_mut_test() {
+        let mut space = Space::new_flat(200u8, 6, 5);
+        let region = Region::new(1, 1, 4, 3);
+
+        let mut visited = Vec::new();
+
+        space.map_region_mut(region, |x, y, value| {
+            visited.push((x, y));
+            *value /= 2;
+        });
+
+        assert_eq!(visited.len(), 12);
+        assert_eq!(visited[..2], [(1, 1), (2, 1)]);
+
+        for y in 0 .. 5 {
+            for x in 0 .. 6 {
+                let expected = if region.contains(x, y) { 100 } else { 200 };
+
+                assert_eq!(*space.get(x, y).unwrap(), expected);
+            }
+        }
+
+        space.map_region_mut(Region::new(5, 4, 10, 10), |_, _, value| *value = 0);
+
+        assert_eq!(space.iter().filter(|v| **v == 0).count(), 1);
+    }
+
+    #[test]
+    fn difference_mask_test() {
+        let before = Space::new_mapped(|x, y| y * 5 + x, 5, 4);
+
+        let mut after = before.clone();
+        after.set(1, 1, 0);
+        after.set(3, 2, 0);
+
+        let mask = before.difference_mask(&after).unwrap();
+
+        assert_eq!(mask.iter().filter(|changed| **changed).count(), 2);
+        assert_eq!((mask.get(1, 1), mask.get(3, 2)), (Some(&true), Some(&true)));
+        assert_eq!(mask.bounding_box(|changed| *changed), Some(Region::new(1, 1, 3, 2)));
+
+        assert!(before.difference_mask(&Space::new_flat(0, 4, 5)).is_none());
+    }
+
+    #[test]
+    fn resize_test() {
+        let original = Space::new_mapped(|x, y| (y * 2 + x) as u8, 2, 2);
+
+        let mut space = original.clone();
+        space.resize(3, 3, 9);
+
+        assert_eq!(space.iter().copied().collect::<Vec<_>>(), vec![
+            0, 1, 9,
+            2, 3, 9,
+            9, 9, 9
+        ]);
+
+        space.resize(2, 2, 7);
+
+        assert_eq!(space, original);
+
+        space.resize(1, 3, 5);
+
+        assert_eq!(space.iter().copied().collect::<Vec<_>>(), vec![0, 2, 5]);
+
+        space.resize(0, 2, 5);
+
+        assert_eq!((space.width(), space.height(), space.len()), (0, 2, 0));
+    }
+}
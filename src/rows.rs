@@ -0,0 +1,364 @@
+use crate::Space;
+use crate::error::*;
+
+/// Assembles a space from lines of equal length, which become the rows of the space
+/// The length of the first line is used as the width, a space without lines is 0x0
+#[inline]
+fn from_lines<U, I>(lines: I) -> Result<Space<U>, ShapeError>
+    where
+        I: Iterator<Item = Vec<U>> {
+
+    let mut data = Vec::new();
+    let mut width = 0;
+    let mut height = 0;
+
+    for (index, line) in lines.enumerate() {
+        if index == 0 {
+            width = line.len();
+        } else if line.len() != width {
+            return Err(ShapeError {
+                index,
+                expected: width,
+                found: line.len()
+            });
+        }
+
+        data.extend(line);
+        height += 1;
+    }
+
+    Ok(Space {
+        data: data.into_boxed_slice(),
+        width,
+        height
+    })
+}
+
+impl<T> Space<T> {
+    /// Creates a space with the provided height from a function that generates each row
+    /// given its index, taking the width from the length of the first row
+    /// If any row has a different length than the first an error describing it is returned
+    #[inline]
+    pub fn from_fn_rows<F: FnMut(usize) -> Vec<T>>(height: usize, func: F) -> Result<Space<T>, ShapeError> {
+        from_lines((0 .. height).map(func))
+    }
+
+    /// Creates a new space by transforming each row of this one
+    /// Every produced row becomes a row of the new space, so they must all have the same length,
+    /// which becomes the width of the new space
+    #[inline]
+    pub fn map_rows<U, F>(&self, mut func: F) -> Result<Space<U>, ShapeError>
+        where
+            F: FnMut(&[T]) -> Vec<U> {
+
+        let width = self.width;
+
+        from_lines((0 .. self.height).map(|y| func(&self.data[y * width .. (y + 1) * width])))
+    }
+
+    /// Creates a new space by transforming each row of this one from top to bottom,
+    /// also passing the function the row it produced for the previous row, which suits
+    /// recurrences such as dynamic programming tables, the first row is passed None instead
+    /// Every produced row becomes a row of the new space, so they must all have the same length,
+    /// which becomes the width of the new space
+    #[inline]
+    pub fn map_rows_scan<U, F>(&self, mut func: F) -> Result<Space<U>, ShapeError>
+        where
+            F: FnMut(Option<&[U]>, &[T]) -> Vec<U> {
+
+        let mut vec: Vec<U> = Vec::new();
+        let mut width = 0;
+
+        for y in 0 .. self.height {
+            let previous = if y > 0 { Some(&vec[(y - 1) * width ..]) } else { None };
+            let produced = func(previous, &self.data[y * self.width .. (y + 1) * self.width]);
+
+            if y == 0 {
+                width = produced.len();
+                vec.reserve(width * self.height);
+            } else if produced.len() != width {
+                return Err(ShapeError {
+                    index: y,
+                    expected: width,
+                    found: produced.len()
+                });
+            }
+
+            vec.extend(produced);
+        }
+
+        Ok(Space {
+            data: vec.into_boxed_slice(),
+            width,
+            height: self.height
+        })
+    }
+
+    /// Creates a new space by transforming each column of this one
+    /// Every produced column becomes a column of the new space, so they must all have the same length,
+    /// which becomes the height of the new space
+    ///
+    /// The columns of the space are not contiguous, so each one is cloned
+    /// into a buffer before being passed to the function
+    #[inline]
+    pub fn map_columns<U, F>(&self, mut func: F) -> Result<Space<U>, ShapeError>
+        where
+            T: Clone,
+            F: FnMut(&[T]) -> Vec<U> {
+
+        let mut column = Vec::with_capacity(self.height);
+        let mut columns = Vec::with_capacity(self.width);
+        let mut height = 0;
+
+        for x in 0 .. self.width {
+            column.clear();
+            column.extend((0 .. self.height).map(|y| self.data[y * self.width + x].clone()));
+
+            let produced = func(&column);
+
+            if x == 0 {
+                height = produced.len();
+            } else if produced.len() != height {
+                return Err(ShapeError {
+                    index: x,
+                    expected: height,
+                    found: produced.len()
+                });
+            }
+
+            columns.push(produced.into_iter());
+        }
+
+        let mut vec = Vec::with_capacity(columns.len() * height);
+
+        for _ in 0 .. height {
+            for produced in columns.iter_mut() {
+                vec.push(produced.next().unwrap());
+            }
+        }
+
+        Ok(Space {
+            data: vec.into_boxed_slice(),
+            width: columns.len(),
+            height
+        })
+    }
+
+    /// Creates an iterator over the rows of the space from the last to the first,
+    /// each row is yielded as a slice so the cells within it stay in left to right order
+    #[inline]
+    pub fn iter_rows_rev(&self) -> impl Iterator<Item = &[T]> {
+        let width = self.width;
+
+        (0 .. self.height).rev().map(move |y| &self.data[y * width .. (y + 1) * width])
+    }
+
+    /// Overwrites every cell of a row with a clone of the corresponding value
+    /// If the row is outside the space or the number of values is not the width
+    /// the space is left untouched and false is returned
+    #[inline]
+    pub fn set_row(&mut self, y: usize, values: &[T]) -> bool
+        where T: Clone {
+
+        if y >= self.height || values.len() != self.width {
+            return false;
+        }
+
+        self.data[y * self.width .. (y + 1) * self.width].clone_from_slice(values);
+
+        true
+    }
+
+    /// Overwrites every cell of a column with a clone of the corresponding value
+    /// If the column is outside the space or the number of values is not the height
+    /// the space is left untouched and false is returned
+    #[inline]
+    pub fn set_column(&mut self, x: usize, values: &[T]) -> bool
+        where T: Clone {
+
+        if x >= self.width || values.len() != self.height {
+            return false;
+        }
+
+        for (y, value) in values.iter().enumerate() {
+            self.data[y * self.width + x] = value.clone();
+        }
+
+        true
+    }
+
+    /// Cyclically shifts the contents of every row,
+    /// positive amounts move cells towards larger x and negative amounts towards smaller x,
+    /// with cells that leave one edge reappearing at the other
+    #[inline]
+    pub fn rotate_rows(&mut self, by: isize) {
+        if self.width == 0 {
+            return;
+        }
+
+        let shift = by.rem_euclid(self.width as isize) as usize;
+
+        for row in self.data.chunks_exact_mut(self.width) {
+            row.rotate_right(shift);
+        }
+    }
+
+    /// Cyclically shifts the contents of every column,
+    /// positive amounts move cells towards larger y and negative amounts towards smaller y,
+    /// with cells that leave one edge reappearing at the other
+    #[inline]
+    pub fn rotate_columns(&mut self, by: isize) {
+        if self.height == 0 {
+            return;
+        }
+
+        // Shifting every column by the same amount moves whole rows,
+        // which are contiguous, so the entire buffer can be rotated at once
+        let shift = by.rem_euclid(self.height as isize) as usize;
+
+        self.data.rotate_right(shift * self.width);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_rows_normalize_test() {
+        let space = Space::new_mapped(|x, y| (x + y + 1) as f32, 4, 3);
+
+        let normalized = space.map_rows(|row| {
+            let total: f32 = row.iter().sum();
+
+            row.iter().map(|v| v / total).collect()
+        }).unwrap();
+
+        let total: f32 = (0 .. 4).map(|x| normalized.get(x, 1).unwrap()).sum();
+
+        assert!((total - 1.0).abs() < 1e-6);
+        assert_eq!(*normalized.get(0, 0).unwrap(), 0.1);
+    }
+
+    #[test]
+    fn map_rows_shape_error_test() {
+        let space = Space::new_mapped(|x, y| x + y, 4, 3);
+
+        let error = space.map_rows(|row| row[.. row[0] + 1].to_vec()).unwrap_err();
+
+        assert_eq!(error, ShapeError { index: 1, expected: 1, found: 2 });
+    }
+
+    #[test]
+    fn map_rows_scan_test() {
+        let space = Space::new_mapped(|x, y| (x * 3 + y) as u32, 3, 4);
+
+        let sums = space.map_rows_scan(|previous, row| match previous {
+            Some(previous) => previous.iter().zip(row).map(|(sum, value)| sum + value).collect(),
+            None => row.to_vec()
+        }).unwrap();
+
+        let totals = (0 .. 3).map(|x| (0 .. 4).map(|y| *space.get(x, y).unwrap()).sum::<u32>()).collect::<Vec<_>>();
+
+        assert_eq!(sums.iter_rows_rev().next().unwrap(), &totals[..]);
+        assert_eq!(sums.get(1, 1), Some(&7));
+
+        let error = space.map_rows_scan(|previous, row| if previous.is_some() { vec![0] } else { row.to_vec() });
+
+        assert_eq!(error, Err(ShapeError { index: 1, expected: 3, found: 1 }));
+    }
+
+    #[test]
+    fn map_columns_test() {
+        // 0 1 2
+        // 3 4 5
+        let space = Space::new_mapped(|x, y| y * 3 + x, 3, 2);
+
+        let doubled = space.map_columns(|column| column.iter().chain(column).copied().collect()).unwrap();
+
+        assert_eq!((doubled.width(), doubled.height()), (3, 4));
+        assert_eq!(doubled.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5, 0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn rotate_rows_test() {
+        // 0 1 2
+        // 3 4 5
+        let mut space = Space::new_mapped(|x, y| y * 3 + x, 3, 2);
+
+        space.rotate_rows(1);
+        assert_eq!(space.iter().copied().collect::<Vec<_>>(), vec![2, 0, 1, 5, 3, 4]);
+
+        space.rotate_rows(-2);
+        assert_eq!(space.iter().copied().collect::<Vec<_>>(), vec![1, 2, 0, 4, 5, 3]);
+
+        let before = space.clone();
+        space.rotate_rows(6);
+        assert_eq!(space, before);
+    }
+
+    #[test]
+    fn rotate_columns_test() {
+        // 0 1
+        // 2 3
+        // 4 5
+        let mut space = Space::new_mapped(|x, y| y * 2 + x, 2, 3);
+
+        space.rotate_columns(-1);
+        assert_eq!(space.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4, 5, 0, 1]);
+
+        space.rotate_columns(4);
+        assert_eq!(space.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn iter_rows_rev_test() {
+        let space = Space::new_mapped(|x, y| y * 3 + x, 3, 4);
+
+        let rows = space.iter_rows_rev().collect::<Vec<_>>();
+
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[0], &[9, 10, 11]);
+        assert_eq!(rows[3], &[0, 1, 2]);
+
+        assert_eq!(Space::new_flat(0u8, 0, 2).iter_rows_rev().count(), 2);
+    }
+
+    #[test]
+    fn set_row_test() {
+        let mut space = Space::new_flat(0, 3, 3);
+
+        assert!(space.set_row(1, &[1, 2, 3]));
+        assert!(!space.set_row(1, &[1, 2]));
+        assert!(!space.set_row(3, &[1, 2, 3]));
+
+        assert_eq!(space.iter().copied().collect::<Vec<_>>(), vec![0, 0, 0, 1, 2, 3, 0, 0, 0]);
+    }
+
+    #[test]
+    fn set_column_test() {
+        let mut space = Space::new_flat(0, 3, 3);
+
+        assert!(space.set_column(2, &[4, 5, 6]));
+        assert!(!space.set_column(0, &[4, 5, 6, 7]));
+        assert!(!space.set_column(3, &[4, 5, 6]));
+
+        assert_eq!(space.iter().copied().collect::<Vec<_>>(), vec![0, 0, 4, 0, 0, 5, 0, 0, 6]);
+    }
+
+    #[test]
+    fn from_fn_rows_test() {
+        let space = Space::from_fn_rows(2, |y| vec![y * 10, y * 10 + 1, y * 10 + 2]).unwrap();
+
+        assert_eq!((space.width(), space.height()), (3, 2));
+        assert_eq!(space.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 10, 11, 12]);
+
+        let ragged = Space::from_fn_rows(3, |y| vec![0u8; if y == 2 { 4 } else { 3 }]);
+
+        assert_eq!(ragged, Err(ShapeError { index: 2, expected: 3, found: 4 }));
+
+        let empty = Space::from_fn_rows(0, |_| vec![1u8]).unwrap();
+
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+    }
+}
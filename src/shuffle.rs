@@ -0,0 +1,158 @@
+use crate::Space;
+use crate::primitives::*;
+
+impl<T> Space<T> {
+    /// Randomly permutes every cell of the space in place with a Fisher-Yates shuffle
+    ///
+    /// The random function is called with a bound n and should return an index below it,
+    /// any larger value is reduced modulo n, so a seeded generator gives a reproducible shuffle
+    /// The cells are only ever swapped, so they do not need to be Clone
+    #[inline]
+    pub fn shuffle_with<F: FnMut(usize) -> usize>(&mut self, mut random: F) {
+        for index in (1 .. self.data.len()).rev() {
+            let other = random(index + 1) % (index + 1);
+
+            self.data.swap(index, other);
+        }
+    }
+
+    /// Randomly permutes the cells of the region in place with a Fisher-Yates shuffle,
+    /// leaving every cell outside the region untouched,
+    /// see `shuffle_with` for the requirements of the random function
+    /// Any part of the region outside the space is ignored
+    #[inline]
+    pub fn shuffle_region_with<F: FnMut(usize) -> usize>(&mut self, region: Region, mut random: F) {
+        let region = region.clamp_to_size(self.width, self.height);
+        let (count, width) = (region.width * region.height, self.width);
+
+        // Converts the index of a cell within the region into its index in the space
+        let locate = |cell: usize| (region.y + cell / region.width) * width + region.x + cell % region.width;
+
+        for cell in (1 .. count).rev() {
+            let other = random(cell + 1) % (cell + 1);
+
+            self.data.swap(locate(cell), locate(other));
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T> Space<T> {
+    /// Randomly permutes every cell of the space in place with a Fisher-Yates shuffle
+    /// drawing from the generator, so a seeded generator gives a reproducible shuffle
+    #[inline]
+    pub fn shuffle<R: rand::Rng>(&mut self, rng: &mut R) {
+        self.shuffle_with(|bound| rng.gen_range(0 .. bound));
+    }
+
+    /// Randomly permutes the cells of the region in place with a Fisher-Yates shuffle
+    /// drawing from the generator, leaving every cell outside the region untouched
+    /// Any part of the region outside the space is ignored
+    #[inline]
+    pub fn shuffle_region<R: rand::Rng>(&mut self, region: Region, rng: &mut R) {
+        self.shuffle_region_with(region, |bound| rng.gen_range(0 .. bound));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small xorshift generator so that the shuffles are reproducible
+    struct XorShift(u64);
+
+    impl XorShift {
+        fn below(&mut self, bound: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+
+            (self.0 % bound as u64) as usize
+        }
+    }
+
+    #[test]
+    fn shuffle_with_test() {
+        let space = Space::new_mapped(|x, y| y * 6 + x, 6, 5);
+
+        let mut first = space.clone();
+        let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+        first.shuffle_with(|bound| rng.below(bound));
+
+        let mut second = space.clone();
+        let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+        second.shuffle_with(|bound| rng.below(bound));
+
+        assert_eq!(first, second);
+        assert_ne!(first, space);
+
+        let mut cells = first.iter().copied().collect::<Vec<_>>();
+        cells.sort_unstable();
+
+        assert!(cells.iter().copied().eq(0 .. 30));
+    }
+
+    #[test]
+    fn shuffle_region_with_test() {
+        let space = Space::new_mapped(|x, y| y * 6 + x, 6, 5);
+        let region = Region::new(1, 1, 3, 3);
+
+        let mut shuffled = space.clone();
+        let mut rng = XorShift(7);
+        shuffled.shuffle_region_with(region, |bound| rng.below(bound));
+
+        let mut inside = Vec::new();
+
+        for (x, y, value) in shuffled.iter_region(shuffled.bounds()) {
+            if region.contains(x, y) {
+                inside.push(*value);
+            } else {
+                assert_eq!(value, space.get(x, y).unwrap());
+            }
+        }
+
+        let mut expected = space.iter_region(region).map(|(_, _, value)| *value).collect::<Vec<_>>();
+
+        assert_ne!(inside, expected);
+
+        inside.sort_unstable();
+        expected.sort_unstable();
+
+        assert_eq!(inside, expected);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn shuffle_rng_test() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let space = Space::new_mapped(|x, y| y * 6 + x, 6, 5);
+
+        let mut first = space.clone();
+        first.shuffle(&mut StdRng::seed_from_u64(42));
+
+        let mut second = space.clone();
+        second.shuffle(&mut StdRng::seed_from_u64(42));
+
+        assert_eq!(first, second);
+        assert_ne!(first, space);
+
+        let mut cells = first.iter().copied().collect::<Vec<_>>();
+        cells.sort_unstable();
+
+        assert!(cells.iter().copied().eq(0 .. 30));
+
+        let region = Region::new(1, 1, 3, 3);
+        let mut shuffled = space.clone();
+        shuffled.shuffle_region(region, &mut StdRng::seed_from_u64(7));
+
+        for (x, y, value) in shuffled.iter_region(shuffled.bounds()) {
+            if !region.contains(x, y) {
+                assert_eq!(value, space.get(x, y).unwrap());
+            }
+        }
+
+        assert_ne!(shuffled, space);
+    }
+}
@@ -0,0 +1,173 @@
+use crate::Space;
+use crate::primitives::*;
+
+/// A SpaceRef represents a rectangular 2 dimensional array stored in a borrowed slice,
+/// so a grid can be read without first copying it into a Space
+/// The rows are stride cells apart in the slice, which lets a SpaceRef view
+/// a region of a larger grid without copying it
+#[derive(Debug)]
+pub struct SpaceRef<'a, T> {
+    /// The borrowed cells, starting at the top left cell of this SpaceRef
+    data: &'a [T],
+
+    /// The distance in the slice between the starts of two consecutive rows
+    stride: usize,
+
+    /// The width (X direction) of this SpaceRef
+    width: usize,
+
+    /// The height (Y direction) of this SpaceRef
+    height: usize
+}
+
+// A slice reference and dimensions are always Copy, so a SpaceRef can be cloned
+// whatever T is, while #[derive(Clone)] would add a T: Clone bound
+impl<'a, T> Clone for SpaceRef<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        SpaceRef {
+            data: self.data,
+            stride: self.stride,
+
+            width: self.width,
+            height: self.height
+        }
+    }
+}
+
+impl<T> Space<T> {
+    /// Creates a borrowed view of the entire space
+    #[inline]
+    pub fn as_space_ref(&self) -> SpaceRef<'_, T> {
+        SpaceRef {
+            data: &self.data,
+            stride: self.width,
+
+            width: self.width,
+            height: self.height
+        }
+    }
+}
+
+impl<'a, T> SpaceRef<'a, T> {
+    /// Creates a view of a slice holding the cells of a grid lexicographically
+    /// If the length of the slice is not width * height None is returned
+    #[inline]
+    pub fn new(data: &'a [T], width: usize, height: usize) -> Option<Self> {
+        if width.checked_mul(height)? != data.len() {
+            return None;
+        }
+
+        Some(SpaceRef {
+            data,
+            stride: width,
+
+            width,
+            height
+        })
+    }
+
+    /// The width (X direction) of this SpaceRef
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height (Y direction) of this SpaceRef
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Creates an immutable reference to the element at a position
+    /// If the position is outside the SpaceRef None is returned
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        self.data.get(y * self.stride + x)
+    }
+
+    /// Creates a view of a region of this SpaceRef, with the position of the region
+    /// relative to this SpaceRef
+    /// If the region does not fit inside this SpaceRef None is returned
+    #[inline]
+    pub fn region_ref(&self, region: Region) -> Option<SpaceRef<'a, T>> {
+        if region.x.checked_add(region.width)? > self.width || region.y.checked_add(region.height)? > self.height {
+            return None;
+        }
+
+        // An empty region borrows nothing so that its start can not lie past the end of the slice
+        let data = if region.width == 0 || region.height == 0 {
+            &self.data[.. 0]
+        } else {
+            let start = region.y * self.stride + region.x;
+
+            &self.data[start .. start + (region.height - 1) * self.stride + region.width]
+        };
+
+        Some(SpaceRef {
+            data,
+            stride: self.stride,
+
+            width: region.width,
+            height: region.height
+        })
+    }
+
+    /// Creates an iterator over the rows of this SpaceRef from top to bottom
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let (data, stride, width) = (self.data, self.stride, self.width);
+
+        (0 .. self.height).map(move |y| &data[y * stride .. y * stride + width])
+    }
+
+    /// Copies the referenced cells into a new owned space
+    #[inline]
+    pub fn to_space(&self) -> Space<T>
+        where T: Clone {
+
+        let mut vec = Vec::with_capacity(self.width * self.height);
+
+        for row in self.rows() {
+            vec.extend_from_slice(row);
+        }
+
+        Space {
+            data: vec.into_boxed_slice(),
+            width: self.width,
+            height: self.height
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn space_ref_test() {
+        let cells = (0 .. 12).collect::<Vec<_>>();
+
+        assert!(SpaceRef::new(&cells, 5, 2).is_none());
+
+        let space_ref = SpaceRef::new(&cells, 4, 3).unwrap();
+        let copy = space_ref.clone();
+
+        assert_eq!(copy.to_space(), Space::new_mapped(|x, y| y * 4 + x, 4, 3));
+        assert_eq!(space_ref.get(3, 2), Some(&11));
+        assert_eq!(space_ref.get(4, 0), None);
+
+        let region = space_ref.region_ref(Region::new(1, 1, 2, 2)).unwrap();
+
+        assert_eq!(region.get(1, 1), Some(&10));
+        assert_eq!(region.get(2, 0), None);
+        assert_eq!(region.to_space().iter().copied().collect::<Vec<_>>(), vec![5, 6, 9, 10]);
+
+        assert!(space_ref.region_ref(Region::new(3, 0, 2, 1)).is_none());
+        assert!(region.region_ref(Region::new(2, 2, 0, 0)).unwrap().to_space().is_empty());
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use crate::Space;
+
+/// A SparseSpace represents a rectangular 2 dimensional array
+/// where only the cells that differ from a background value are stored
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct SparseSpace<T> {
+    /// The cells that differ from the background, keyed by their (x, y) position
+    cells: HashMap<(usize, usize), T>,
+
+    /// The width (X direction) of the SparseSpace
+    width: usize,
+
+    /// The height (Y direction) of the SparseSpace
+    height: usize
+}
+
+impl<T> Space<T> {
+    /// Creates a sparse copy of this space which only records
+    /// the cells that are not equal to the background value
+    #[inline]
+    pub fn to_sparse(&self, background: &T) -> SparseSpace<T>
+        where T: PartialEq + Clone {
+
+        let mut cells = HashMap::new();
+
+        for (index, value) in self.data.iter().enumerate() {
+            if value != background {
+                cells.insert((index % self.width, index / self.width), value.clone());
+            }
+        }
+
+        SparseSpace {
+            cells,
+            width: self.width,
+            height: self.height
+        }
+    }
+}
+
+impl<T> SparseSpace<T> {
+    /// The width (X direction) of the SparseSpace
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height (Y direction) of the SparseSpace
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cells that differ from the background, keyed by their (x, y) position
+    #[inline]
+    pub fn cells(&self) -> &HashMap<(usize, usize), T> {
+        &self.cells
+    }
+
+    /// Creates an immutable reference to the stored element at a position
+    /// If the position holds the background value or is outside the space None is returned
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.cells.get(&(x, y))
+    }
+
+    /// Reconstructs the full space, filling every unrecorded cell with the background value
+    #[inline]
+    pub fn to_dense(&self, background: T) -> Space<T>
+        where T: Clone {
+
+        let mut space = Space::new_flat(background, self.width, self.height);
+
+        for ((x, y), value) in self.cells.iter() {
+            space.set(*x, *y, value.clone());
+        }
+
+        space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparse_round_trip_test() {
+        let mut space = Space::new_flat(0u8, 20, 15);
+
+        space.set(0, 0, 3);
+        space.set(19, 0, 7);
+        space.set(4, 9, 1);
+        space.set(19, 14, 9);
+
+        let sparse = space.to_sparse(&0);
+
+        assert_eq!(sparse.cells().len(), 4);
+        assert_eq!(sparse.width(), 20);
+        assert_eq!(sparse.height(), 15);
+        assert_eq!(sparse.get(4, 9), Some(&1));
+        assert_eq!(sparse.get(5, 9), None);
+
+        assert_eq!(sparse.to_dense(0), space);
+    }
+}
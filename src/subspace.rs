@@ -1,9 +1,9 @@
-use crate::Space;
+use crate::{Space, BoundsError, SplitError};
 use crate::primitives::*;
 
 /// The data structure that represents a read-only view of a subspace
 /// of some parent space
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct SubSpace<'a, T> {
     /// The space that this SubSpace is from
     parent: &'a Space<T>,
@@ -21,6 +21,51 @@ pub struct SubSpace<'a, T> {
     height: usize
 }
 
+/// Converts a ratio of a length into an offset along it, rounding to the nearest
+/// whole cell and clamping into the range 0 to length
+#[inline]
+pub(crate) fn ratio_offset(ratio: f32, length: usize) -> usize {
+    // Negative and NaN products saturate to 0 when cast
+    ((ratio * length as f32).round() as usize).min(length)
+}
+
+/// Resolves the point of a four way split of a slice covering the region into absolute coordinates
+/// If either coordinate lies outside the region the error identifies it, checking X first
+#[inline]
+pub(crate) fn resolve_quadrants(pos_type: PositioningType, x: usize, y: usize, region: Region) -> Result<(usize, usize), SplitError> {
+    let (x, y) = match pos_type {
+        PositioningType::Absolute => (x, y),
+        PositioningType::Relative => (region.x.saturating_add(x), region.y.saturating_add(y))
+    };
+
+    if x < region.x || x > region.x + region.width {
+        return Err(SplitError::Horizontal { x, start: region.x, end: region.x + region.width });
+    }
+
+    if y < region.y || y > region.y + region.height {
+        return Err(SplitError::Vertical { y, start: region.y, end: region.y + region.height });
+    }
+
+    Ok((x, y))
+}
+
+// Cloning a SubSpace only copies the reference to its parent,
+// so unlike a derived implementation this does not require T to be Clone
+impl<'a, T> Clone for SubSpace<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        SubSpace {
+            parent: self.parent,
+
+            x: self.x,
+            y: self.y,
+
+            width: self.width,
+            height: self.height
+        }
+    }
+}
+
 impl<T> Space<T> {
     /// Create a read only slice representing the entire space
     #[inline]
@@ -35,6 +80,58 @@ impl<T> Space<T> {
             height: self.height
         }
     }
+
+    /// Divides the space into bands of exactly the given number of rows, ordered top to bottom,
+    /// along with a band of the leftover rows at the bottom if the height is not divisible
+    ///
+    /// Panics if the band height is zero
+    #[inline]
+    pub fn chunks_exact_rows(&self, band_height: usize) -> (impl Iterator<Item = SubSpace<'_, T>>, Option<SubSpace<'_, T>>) {
+        if band_height == 0 {
+            panic!("Invalid band height (0), bands must contain at least one row");
+        }
+
+        let bands = self.height / band_height;
+        let leftover = self.height % band_height;
+
+        let band = move |y: usize, height: usize| SubSpace {
+            parent: self,
+
+            x: 0,
+            y,
+
+            width: self.width,
+            height
+        };
+
+        let remainder = if leftover == 0 {
+            None
+        } else {
+            Some(band(bands * band_height, leftover))
+        };
+
+        ((0 .. bands).map(move |index| band(index * band_height, band_height)), remainder)
+    }
+
+    /// Create a read only slice representing the rectangle with its top left corner
+    /// at the given position and the given dimensions
+    /// If the rectangle does not fit inside the space None is returned
+    #[inline]
+    pub fn as_subspace_at(&self, x: usize, y: usize, width: usize, height: usize) -> Option<SubSpace<'_, T>> {
+        if x.checked_add(width)? > self.width || y.checked_add(height)? > self.height {
+            return None;
+        }
+
+        Some(SubSpace {
+            parent: self,
+
+            x,
+            y,
+
+            width,
+            height
+        })
+    }
 }
 
 impl<'a, T> SubSpace<'a, T> {
@@ -50,10 +147,22 @@ impl<'a, T> SubSpace<'a, T> {
         self.height
     }
 
+    /// The number of cells in the SubSpace
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.width * self.height
+    }
+
+    /// Whether the SubSpace has no cells, which is the case when either dimension is zero
     #[inline]
-    fn convert_coord(&self, pos_type: PostioningType, x: usize, y: usize) -> Option<(usize, usize)> {
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    fn convert_coord(&self, pos_type: PositioningType, x: usize, y: usize) -> Option<(usize, usize)> {
         match pos_type {
-            PostioningType::Absolute =>  {
+            PositioningType::Absolute =>  {
                 if x < self.x 
                     || x >= self.x + self.width
                     || y < self.y
@@ -64,8 +173,8 @@ impl<'a, T> SubSpace<'a, T> {
                     Some((x, y))
                 }
             }
-            PostioningType::Relative => {
-                if x > self.width || y > self.height {
+            PositioningType::Relative => {
+                if x >= self.width || y >= self.height {
                     None
                 } else {
                     Some((self.x + x, self.y + y))
@@ -78,21 +187,128 @@ impl<'a, T> SubSpace<'a, T> {
     /// the specified addressing mode
     /// If the value queried is outside the slice None will be returned
     #[inline]
-    pub fn get(&self, pos_type: PostioningType, x: usize, y: usize) -> Option<&T> {
+    pub fn get(&self, pos_type: PositioningType, x: usize, y: usize) -> Option<&'a T> {
         let (abs_x, abs_y) = self.convert_coord(pos_type, x, y)?;
 
         self.parent.get(abs_x, abs_y)
     }
 
+    /// Steps from a relative position by (dx, dy) at a time, which suits line of sight checks,
+    /// and finds the relative position of the first cell that blocks the ray
+    /// The start cell itself is never tested
+    /// If the ray leaves the SubSpace first, or the step is (0, 0), None is returned
+    #[inline]
+    pub fn cast_ray<F: Fn(&T) -> bool>(&self, x: usize, y: usize, dx: isize, dy: isize, blocks: F) -> Option<(usize, usize)> {
+        if dx == 0 && dy == 0 {
+            return None;
+        }
+
+        let (mut x, mut y) = (x, y);
+
+        loop {
+            x = x.checked_add_signed(dx)?;
+            y = y.checked_add_signed(dy)?;
+
+            if blocks(self.get(PositioningType::Relative, x, y)?) {
+                return Some((x, y));
+            }
+        }
+    }
+
     /// Creates an iterator that reads through the SubSpace lexicographically
-    pub fn iter(&self) -> SubSpaceIter<'_, T> {
+    pub fn iter(&self) -> SubSpaceIter<'a, T> {
         SubSpaceIter {
-            parent: self,
-            x: 0,
-            y: 0
+            parent: self.parent,
+            x: self.x,
+            width: self.width,
+
+            y: self.y,
+            end_y: self.y + self.height,
+
+            row: [].iter()
         }
     }
 
+    /// Creates an iterator that reads through the SubSpace lexicographically,
+    /// after checking that the SubSpace lies entirely inside its parent
+    /// If it extends outside the parent an error describing it is returned
+    #[inline]
+    pub fn checked_iter(&self) -> Result<SubSpaceIter<'a, T>, BoundsError> {
+        let inside = self.x.checked_add(self.width).is_some_and(|right| right <= self.parent.width())
+            && self.y.checked_add(self.height).is_some_and(|bottom| bottom <= self.parent.height());
+
+        if !inside {
+            return Err(BoundsError {
+                region: Region::new(self.x, self.y, self.width, self.height),
+                width: self.parent.width(),
+                height: self.parent.height()
+            });
+        }
+
+        Ok(self.iter())
+    }
+
+    /// Creates an iterator over the relative position and value of every cell
+    /// where the sum of the relative coordinates has the given parity,
+    /// reading the SubSpace lexicographically
+    /// A parity of 0 selects the cells where x + y is even and 1 the cells where it is odd,
+    /// like the two colours of a checkerboard no two cells of one parity are orthogonally adjacent
+    #[inline]
+    pub fn iter_parity(&self, parity: u8) -> impl Iterator<Item = (usize, usize, &'a T)> {
+        let width = self.width;
+
+        self.iter()
+            .enumerate()
+            .map(move |(index, value)| (index % width, index / width, value))
+            .filter(move |(x, y, _)| (x + y) % 2 == parity as usize)
+    }
+
+    /// Creates an iterator over the relative position and value of every cell
+    /// within the diagonal band where |x - y| <= bandwidth, reading the SubSpace lexicographically,
+    /// which skips the cells outside the band of a banded matrix
+    /// A bandwidth of 0 selects only the main diagonal
+    #[inline]
+    pub fn iter_band(&self, bandwidth: usize) -> impl Iterator<Item = (usize, usize, &'a T)> {
+        let width = self.width;
+
+        self.iter()
+            .enumerate()
+            .map(move |(index, value)| (index % width, index / width, value))
+            .filter(move |(x, y, _)| x.abs_diff(*y) <= bandwidth)
+    }
+
+    /// Creates an iterator that reads through the SubSpace lexicographically
+    /// until the first cell for which the predicate, given its relative position and value,
+    /// returns false, and then stops rather than skipping that cell
+    #[inline]
+    pub fn iter_take_while<F: Fn(usize, usize, &T) -> bool>(&self, pred: F) -> impl Iterator<Item = &'a T> {
+        let width = self.width;
+
+        self.iter()
+            .enumerate()
+            .take_while(move |(index, value)| pred(index % width, index / width, value))
+            .map(|(_, value)| value)
+    }
+
+    /// Creates an iterator that reads copies of the values of the SubSpace lexicographically
+    #[inline]
+    pub fn iter_copied(&self) -> impl Iterator<Item = T> + 'a
+        where T: Copy {
+
+        self.iter().copied()
+    }
+
+    /// Creates an iterator over the absolute position in the parent space and value
+    /// of every cell, reading the SubSpace lexicographically
+    #[inline]
+    pub fn iter_absolute(&self) -> impl Iterator<Item = (usize, usize, &'a T)> {
+        let (x, y, width) = (self.x, self.y, self.width);
+
+        self.iter()
+            .enumerate()
+            .map(move |(index, value)| (x + index % width, y + index / width, value))
+    }
+
     pub fn as_space(&'a self) -> Space<T>
         where
             T: Clone + 'static {
@@ -106,16 +322,16 @@ impl<'a, T> SubSpace<'a, T> {
     /// The left subspace contains all the points in this one that have x less than the given x_value
     /// The right subspace contains all the points in this one that have x greater than or equal to the given x_value
     #[inline]
-    pub fn split_horizontal(&self, pos_type: PostioningType, x_value: usize) -> HorizontalSplit<SubSpace<'a, T>> {
+    pub fn split_horizontal(&self, pos_type: PositioningType, x_value: usize) -> HorizontalSplit<SubSpace<'a, T>> {
         let left_x = self.x;
 
         let right_x = match pos_type {
-            PostioningType::Absolute => x_value,
-            PostioningType::Relative => self.x + x_value
+            PositioningType::Absolute => x_value,
+            PositioningType::Relative => self.x + x_value
         };
 
-        if right_x > self.width {
-            panic!("Invalid x value ({}) provided for slice with width {}", right_x, self.width);
+        if right_x < self.x || right_x > self.x + self.width {
+            panic!("Invalid x value ({}) provided for slice spanning x values {} to {}", right_x, self.x, self.x + self.width);
         }
         
         let left_width = right_x - left_x;
@@ -147,16 +363,16 @@ impl<'a, T> SubSpace<'a, T> {
     /// The above subspace contains all the points in this one that have y less than the given y_value
     /// The below subspace contains all the points in this one that have y greater than or equal to the given y_value
     #[inline]
-    pub fn split_vertical(&self, pos_type: PostioningType, y_value: usize) -> VerticalSplit<SubSpace<'a, T>> {
+    pub fn split_vertical(&self, pos_type: PositioningType, y_value: usize) -> VerticalSplit<SubSpace<'a, T>> {
         let above_y = self.y;
 
         let below_y = match pos_type {
-            PostioningType::Absolute => y_value,
-            PostioningType::Relative => self.y + y_value
+            PositioningType::Absolute => y_value,
+            PositioningType::Relative => self.y + y_value
         };
 
-        if below_y > self.height {
-            panic!("Invalid y value ({}) provided for slice with height {}", below_y, self.height);
+        if below_y < self.y || below_y > self.y + self.height {
+            panic!("Invalid y value ({}) provided for slice spanning y values {} to {}", below_y, self.y, self.y + self.height);
         }
         
         let above_height = below_y - above_y;
@@ -184,32 +400,167 @@ impl<'a, T> SubSpace<'a, T> {
             }
         }
     }
+
+    /// Splits this SubSpace into four slices which meet at the provided point
+    /// Both coordinates are checked before splitting, so if either lies outside
+    /// this SubSpace an error identifying its axis is returned instead of panicking
+    #[inline]
+    pub fn try_split_quadrants(&self, pos_type: PositioningType, x: usize, y: usize) -> Result<Quadrants<SubSpace<'a, T>>, SplitError> {
+        let region = Region::new(self.x, self.y, self.width, self.height);
+        let (x, y) = resolve_quadrants(pos_type, x, y, region)?;
+
+        let HorizontalSplit { left, right } = self.split_horizontal(PositioningType::Absolute, x);
+        let VerticalSplit { above: top_left, below: bottom_left } = left.split_vertical(PositioningType::Absolute, y);
+        let VerticalSplit { above: top_right, below: bottom_right } = right.split_vertical(PositioningType::Absolute, y);
+
+        Ok(Quadrants {
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right
+        })
+    }
+
+    /// Splits this SubSpace horizontally at a fraction of its width, so a ratio of 0.3
+    /// gives a left slice covering 30% of the width rounded to the nearest cell
+    /// The ratio is clamped so that 0.0 or less gives an empty left slice
+    /// and 1.0 or more gives an empty right slice
+    #[inline]
+    pub fn split_horizontal_ratio(&self, ratio: f32) -> HorizontalSplit<SubSpace<'a, T>> {
+        self.split_horizontal(PositioningType::Relative, ratio_offset(ratio, self.width))
+    }
+
+    /// Splits this SubSpace vertically at a fraction of its height, so a ratio of 0.3
+    /// gives an above slice covering 30% of the height rounded to the nearest cell
+    /// The ratio is clamped so that 0.0 or less gives an empty above slice
+    /// and 1.0 or more gives an empty below slice
+    #[inline]
+    pub fn split_vertical_ratio(&self, ratio: f32) -> VerticalSplit<SubSpace<'a, T>> {
+        self.split_vertical(PositioningType::Relative, ratio_offset(ratio, self.height))
+    }
+
+    /// Splits this SubSpace into n bands of equal width, ordered left to right
+    /// When the width is not divisible by n the last band absorbs the remainder,
+    /// so a width of 7 split into 3 gives bands of width 2, 2 and 3
+    /// If n is 0 no bands are returned
+    #[inline]
+    pub fn split_n_horizontal(&self, n: usize) -> Vec<SubSpace<'a, T>> {
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let band_width = self.width / n;
+
+        (0 .. n)
+            .map(|band| SubSpace {
+                parent: self.parent,
+
+                x: self.x + band * band_width,
+                width: if band == n - 1 { self.width - band * band_width } else { band_width },
+
+                y: self.y,
+                height: self.height
+            })
+            .collect()
+    }
+
+    /// Splits this SubSpace into n bands of equal height, ordered top to bottom
+    /// When the height is not divisible by n the last band absorbs the remainder,
+    /// so a height of 7 split into 3 gives bands of height 2, 2 and 3
+    /// If n is 0 no bands are returned
+    #[inline]
+    pub fn split_n_vertical(&self, n: usize) -> Vec<SubSpace<'a, T>> {
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let band_height = self.height / n;
+
+        (0 .. n)
+            .map(|band| SubSpace {
+                parent: self.parent,
+
+                y: self.y + band * band_height,
+                height: if band == n - 1 { self.height - band * band_height } else { band_height },
+
+                x: self.x,
+                width: self.width
+            })
+            .collect()
+    }
+
+    /// Splits this SubSpace into a grid of tiles, with rows bands of equal height
+    /// each split into cols bands of equal width, see `split_n_horizontal` and `split_n_vertical`
+    /// The tile in row r and column c is at `grid[r][c]`
+    /// If either count is 0 no tiles are returned
+    #[inline]
+    pub fn split_into_grid(&self, cols: usize, rows: usize) -> Vec<Vec<SubSpace<'a, T>>> {
+        if cols == 0 {
+            return Vec::new();
+        }
+
+        self.split_n_vertical(rows)
+            .iter()
+            .map(|band| band.split_n_horizontal(cols))
+            .collect()
+    }
 }
 
+/// An iterator that reads through a SubSpace lexicographically
+///
+/// Each row of a SubSpace is contiguous in the parent space,
+/// so the iterator walks a row slice and only computes an index at the start of each row
 pub struct SubSpaceIter<'a, T> {
-    parent: &'a SubSpace<'a, T>,
+    /// The space that the SubSpace is from
+    parent: &'a Space<T>,
 
+    /// The X position that every row starts at
     x: usize,
-    y: usize
+
+    /// The width (X direction) of every row
+    width: usize,
+
+    /// The Y position of the next row to be read
+    y: usize,
+
+    /// The Y position one past the last row
+    end_y: usize,
+
+    /// The remainder of the row currently being read
+    row: std::slice::Iter<'a, T>
 }
 
 impl<'a, T> Iterator for SubSpaceIter<'a, T> {
     type Item = &'a T;
 
+    #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        let result = self.parent.get(PostioningType::Relative, self.x, self.y);
+        loop {
+            if let Some(result) = self.row.next() {
+                return Some(result);
+            }
 
-        if self.x == self.parent.width - 1 {
-            self.x = 0;
+            if self.y >= self.end_y {
+                return None;
+            }
+
+            let start = self.y * self.parent.width + self.x;
+
+            self.row = self.parent.data[start .. start + self.width].iter();
             self.y += 1;
-        } else {
-            self.x += 1;
         }
+    }
 
-        result
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.row.len() + (self.end_y - self.y) * self.width;
+
+        (remaining, Some(remaining))
     }
 }
 
+impl<'a, T> ExactSizeIterator for SubSpaceIter<'a, T> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,12 +576,32 @@ mod tests {
         assert!(subspace_iter.eq(counter));
     }
 
+    #[test]
+    fn iter_offset_subspace_test() {
+        let space = Space::new_mapped(|x, y| (x, y), 9, 7);
+        let subspace = space.as_subspace();
+
+        let HorizontalSplit { right, .. } = subspace.split_horizontal(PositioningType::Absolute, 3);
+        let VerticalSplit { below, .. } = right.split_vertical(PositioningType::Absolute, 2);
+
+        let mut expected = Vec::new();
+
+        for y in 0 .. below.height() {
+            for x in 0 .. below.width() {
+                expected.push(below.get(PositioningType::Relative, x, y).unwrap());
+            }
+        }
+
+        assert_eq!(below.iter().len(), 30);
+        assert!(below.iter().eq(expected.into_iter()));
+    }
+
     #[test]
     fn horizontal_split_width_check() {
         let space = Space::new_flat(1u32, 4, 4);
         let space_slice = space.as_subspace();
 
-        let HorizontalSplit { left, right } = space_slice.split_horizontal(PostioningType::Absolute, 2);
+        let HorizontalSplit { left, right } = space_slice.split_horizontal(PositioningType::Absolute, 2);
 
         assert_eq!(left.width(), 2);
         assert_eq!(right.width(), 2);
@@ -241,23 +612,285 @@ mod tests {
         let space = Space::new_flat(1u32, 4, 4);
         let space_slice = space.as_subspace();
 
-        let VerticalSplit { above, below } = space_slice.split_vertical(PostioningType::Absolute, 2);
+        let VerticalSplit { above, below } = space_slice.split_vertical(PositioningType::Absolute, 2);
 
         assert_eq!(above.height(), 2);
         assert_eq!(below.height(), 2);
     }
 
+    #[test]
+    fn relative_get_bounds_test() {
+        let space = Space::new_mapped(|x, y| (x, y), 4, 4);
+        let subspace = space.as_subspace();
+
+        let HorizontalSplit { left, .. } = subspace.split_horizontal(PositioningType::Absolute, 2);
+
+        assert_eq!(left.get(PositioningType::Relative, 1, 3), Some(&(1, 3)));
+        assert_eq!(left.get(PositioningType::Relative, 2, 0), None);
+        assert_eq!(left.get(PositioningType::Relative, 0, 4), None);
+    }
+
+    #[test]
+    fn split_n_test() {
+        let space = Space::new_mapped(|x, y| (x, y), 7, 5);
+        let subspace = space.as_subspace();
+
+        let columns = subspace.split_n_horizontal(3);
+
+        assert_eq!(columns.iter().map(|band| band.width()).collect::<Vec<_>>(), vec![2, 2, 3]);
+        assert_eq!(columns[2].get(PositioningType::Relative, 0, 0), Some(&(4, 0)));
+        assert_eq!(columns[1].iter().count(), 10);
+
+        let rows = columns[2].split_n_vertical(2);
+
+        assert_eq!(rows.iter().map(|band| band.height()).collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(rows[1].get(PositioningType::Relative, 2, 2), Some(&(6, 4)));
+
+        assert!(subspace.split_n_vertical(0).is_empty());
+    }
+
+    #[test]
+    fn len_test() {
+        let space = Space::new_flat(0u8, 5, 4);
+        let subspace = space.as_subspace();
+
+        let HorizontalSplit { left, right } = subspace.split_horizontal(PositioningType::Absolute, 0);
+
+        assert_eq!(subspace.len(), 20);
+        assert!(left.is_empty());
+        assert_eq!(right.len(), 20);
+        assert!(!right.is_empty());
+    }
+
+    #[test]
+    fn as_subspace_at_test() {
+        let space = Space::new_mapped(|x, y| (x, y), 4, 4);
+
+        let view = space.as_subspace_at(1, 2, 2, 2).unwrap();
+
+        assert_eq!(view.iter().copied().collect::<Vec<_>>(), vec![(1, 2), (2, 2), (1, 3), (2, 3)]);
+        assert!(space.as_subspace_at(3, 0, 2, 1).is_none());
+        assert!(space.as_subspace_at(0, 0, 4, 5).is_none());
+        assert!(space.as_subspace_at(usize::MAX, 0, 2, 1).is_none());
+    }
+
+    #[test]
+    fn iter_parity_test() {
+        let space = Space::new_mapped(|x, y| (x, y), 5, 3);
+        let subspace = space.as_subspace();
+
+        let even = subspace.iter_parity(0).collect::<Vec<_>>();
+        let odd = subspace.iter_parity(1).collect::<Vec<_>>();
+
+        assert_eq!(even.len(), 8);
+        assert_eq!(odd.len(), 7);
+        assert_eq!(even[..3], [(0, 0, &(0, 0)), (2, 0, &(2, 0)), (4, 0, &(4, 0))]);
+        assert!(even.iter().all(|(x, y, _)| (x + y) % 2 == 0));
+        assert!(odd.iter().all(|(x, y, _)| (x + y) % 2 == 1));
+
+        let mut all = even.iter().chain(odd.iter()).map(|(x, y, _)| (*x, *y)).collect::<Vec<_>>();
+        all.sort_by_key(|(x, y)| (*y, *x));
+
+        assert!(all.iter().eq(subspace.iter()));
+    }
+
+    #[test]
+    fn split_ratio_test() {
+        let space = Space::new_flat(0u8, 10, 4);
+        let subspace = space.as_subspace();
+
+        let HorizontalSplit { left, right } = subspace.split_horizontal_ratio(0.3);
+
+        assert_eq!((left.width(), right.width()), (3, 7));
+
+        let VerticalSplit { above, below } = subspace.split_vertical_ratio(1.5);
+
+        assert_eq!((above.height(), below.height()), (4, 0));
+        assert_eq!(subspace.split_horizontal_ratio(-1.0).left.width(), 0);
+    }
+
+    #[test]
+    fn try_split_quadrants_test() {
+        let space = Space::new_mapped(|x, y| (x, y), 6, 5);
+        let subspace = space.as_subspace_at(1, 1, 4, 3).unwrap();
+
+        let Quadrants { top_left, top_right, bottom_left, bottom_right } = subspace.try_split_quadrants(PositioningType::Relative, 1, 2).unwrap();
+
+        assert_eq!((top_left.width(), top_left.height()), (1, 2));
+        assert_eq!((top_right.width(), top_right.height()), (3, 2));
+        assert_eq!((bottom_left.width(), bottom_left.height()), (1, 1));
+        assert_eq!(bottom_right.get(PositioningType::Relative, 0, 0), Some(&(2, 3)));
+
+        assert_eq!(subspace.try_split_quadrants(PositioningType::Absolute, 0, 9).err(), Some(SplitError::Horizontal { x: 0, start: 1, end: 5 }));
+        assert_eq!(subspace.try_split_quadrants(PositioningType::Relative, 4, 4).err(), Some(SplitError::Vertical { y: 5, start: 1, end: 4 }));
+    }
+
+    #[test]
+    fn iter_copied_test() {
+        let space = Space::new_mapped(|x, y| x as i32 - y as i32, 5, 4);
+
+        assert_eq!(space.as_subspace().iter_copied().sum::<i32>(), 10 * 4 - 6 * 5);
+        assert_eq!(space.as_subspace_at(3, 1, 2, 2).unwrap().iter_copied().sum::<i32>(), 8);
+    }
+
+    #[test]
+    fn split_into_grid_test() {
+        let space = Space::new_mapped(|x, y| (x, y), 10, 10);
+
+        let grid = space.as_subspace().split_into_grid(3, 3);
+
+        assert_eq!(grid.len(), 3);
+        assert!(grid.iter().all(|row| row.len() == 3));
+
+        assert_eq!((grid[0][0].width(), grid[0][0].height()), (3, 3));
+        assert_eq!((grid[0][2].width(), grid[0][2].height()), (4, 3));
+        assert_eq!((grid[2][0].width(), grid[2][0].height()), (3, 4));
+        assert_eq!((grid[2][2].width(), grid[2][2].height()), (4, 4));
+        assert_eq!(grid[2][2].get(PositioningType::Relative, 0, 0), Some(&(6, 6)));
+
+        assert!(space.as_subspace().split_into_grid(0, 3).is_empty());
+    }
+
+    #[test]
+    fn checked_iter_test() {
+        let space = Space::new_mapped(|x, y| (x, y), 4, 3);
+
+        let inside = space.as_subspace_at(1, 1, 3, 2).unwrap();
+
+        assert!(inside.checked_iter().unwrap().eq(inside.iter()));
+
+        // Built directly, as a faulty split might, this view runs past the right edge
+        let overextended = SubSpace {
+            parent: &space,
+
+            x: 2,
+            y: 1,
+
+            width: 3,
+            height: 2
+        };
+
+        let error = overextended.checked_iter().err().unwrap();
+
+        assert_eq!(error.region, Region::new(2, 1, 3, 2));
+        assert_eq!((error.width, error.height), (4, 3));
+    }
+
+    #[test]
+    fn iter_absolute_test() {
+        let space = Space::new_mapped(|x, y| (x, y), 6, 4);
+        let subspace = space.as_subspace_at(2, 1, 3, 2).unwrap();
+
+        let cells = subspace.iter_absolute().collect::<Vec<_>>();
+
+        assert_eq!(cells.len(), 6);
+        assert_eq!(cells[0], (2, 1, &(2, 1)));
+        assert_eq!(cells[5], (4, 2, &(4, 2)));
+        assert!(cells.iter().all(|(x, y, value)| (*x, *y) == **value));
+    }
+
+    #[test]
+    fn chunks_exact_rows_test() {
+        let space = Space::new_mapped(|x, y| (x, y), 3, 7);
+
+        let (bands, remainder) = space.chunks_exact_rows(2);
+        let bands = bands.collect::<Vec<_>>();
+
+        assert_eq!(bands.len(), 3);
+        assert!(bands.iter().all(|band| band.height() == 2 && band.width() == 3));
+        assert_eq!(bands[2].get(PositioningType::Relative, 0, 0), Some(&(0, 4)));
+
+        let remainder = remainder.unwrap();
+
+        assert_eq!(remainder.height(), 1);
+        assert_eq!(remainder.get(PositioningType::Relative, 2, 0), Some(&(2, 6)));
+
+        assert!(space.chunks_exact_rows(7).1.is_none());
+    }
+
+    #[test]
+    fn nested_split_test() {
+        let space = Space::new_mapped(|x, y| (x, y), 10, 10);
+        let subspace = space.as_subspace();
+
+        let HorizontalSplit { right, .. } = subspace.split_horizontal(PositioningType::Absolute, 4);
+        let HorizontalSplit { left, right } = right.split_horizontal(PositioningType::Relative, 2);
+
+        assert_eq!((left.width(), right.width()), (2, 4));
+        assert_eq!(right.get(PositioningType::Relative, 0, 0), Some(&(6, 0)));
+
+        let VerticalSplit { below, .. } = right.split_vertical(PositioningType::Absolute, 7);
+        let VerticalSplit { above, below } = below.split_vertical(PositioningType::Absolute, 9);
+
+        assert_eq!((above.height(), below.height()), (2, 1));
+        assert_eq!(below.get(PositioningType::Relative, 3, 0), Some(&(9, 9)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_before_start_test() {
+        let space = Space::new_flat(0u8, 10, 10);
+        let subspace = space.as_subspace();
+
+        let HorizontalSplit { right, .. } = subspace.split_horizontal(PositioningType::Absolute, 4);
+
+        right.split_horizontal(PositioningType::Absolute, 2);
+    }
+
     #[test]
     fn partition_test() {
         let space = Space::new_mapped(|x, _| x < 10, 20, 20);
         let subspace = space.as_subspace();
 
-        let HorizontalSplit { left, right } = subspace.split_horizontal(PostioningType::Absolute, 10);
+        let HorizontalSplit { left, right } = subspace.split_horizontal(PositioningType::Absolute, 10);
 
         assert!(left.iter().all(|v| *v));
         assert!(right.iter().all(|v| !*v));
     }
 
+    #[test]
+    fn cast_ray_test() {
+        let space = Space::new_mapped(|x, y| x == 6 || (x == 2 && y == 3), 10, 4);
+        let subspace = space.as_subspace_at(1, 2, 8, 2).unwrap();
+
+        assert_eq!(subspace.cast_ray(0, 0, 1, 0, |wall| *wall), Some((5, 0)));
+        assert_eq!(subspace.cast_ray(5, 0, 1, 0, |wall| *wall), None);
+        assert_eq!(subspace.cast_ray(5, 0, -1, 0, |wall| *wall), None);
+        assert_eq!(subspace.cast_ray(2, 0, -1, 1, |wall| *wall), Some((1, 1)));
+        assert_eq!(subspace.cast_ray(0, 0, 0, 0, |wall| *wall), None);
+    }
+
+    #[test]
+    fn iter_band_test() {
+        let space = Space::new_mapped(|x, y| (x, y), 7, 7);
+        let subspace = space.as_subspace_at(1, 1, 5, 5).unwrap();
+
+        let band = subspace.iter_band(1).map(|(x, y, _)| (x, y)).collect::<Vec<_>>();
+
+        assert_eq!(band, vec![
+            (0, 0), (1, 0),
+            (0, 1), (1, 1), (2, 1),
+            (1, 2), (2, 2), (3, 2),
+            (2, 3), (3, 3), (4, 3),
+            (3, 4), (4, 4)
+        ]);
+        assert!(subspace.iter_band(1).all(|(x, y, value)| *value == (x + 1, y + 1)));
+
+        assert!(subspace.iter_band(0).all(|(x, y, _)| x == y));
+        assert_eq!(subspace.iter_band(0).count(), 5);
+        assert_eq!(subspace.iter_band(4).count(), 25);
+    }
+
+    #[test]
+    fn iter_take_while_test() {
+        let space = Space::new_mapped(|x, y| (x, y) != (2, 2) && (x, y) != (0, 3), 5, 5);
+        let subspace = space.as_subspace_at(1, 1, 4, 3).unwrap();
+
+        assert_eq!(subspace.iter_take_while(|_, _, open| *open).count(), 5);
+        assert_eq!(subspace.iter_take_while(|_, y, _| y < 2).count(), 8);
+        assert_eq!(subspace.iter_take_while(|_, _, _| true).count(), 12);
+    }
+
     #[test]
     fn clone_test() {
         let original = Space::new_mapped(|x, y| (x, y), 100, 100);
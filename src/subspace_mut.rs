@@ -1,7 +1,11 @@
 use std::marker::PhantomData;
+use std::ptr::addr_of_mut;
 
-use crate::Space;
+use crate::{Space, SubSpace};
 use crate::primitives::*;
+use crate::region::in_border;
+use crate::SplitError;
+use crate::subspace::{ratio_offset, resolve_quadrants};
 
 /// The data structure that represents a mutable view of a subspace
 /// of some parent space
@@ -48,6 +52,55 @@ impl<T> Space<T> {
             height: self.height
         }
     }
+
+    /// Create a mutable slice representing the rectangle with its top left corner
+    /// at the given position and the given dimensions
+    /// If the rectangle does not fit inside the space None is returned
+    #[inline]
+    pub fn as_subspace_mut_at(&mut self, x: usize, y: usize, width: usize, height: usize) -> Option<SubSpaceMut<'_, T>> {
+        if x.checked_add(width)? > self.width || y.checked_add(height)? > self.height {
+            return None;
+        }
+
+        Some(SubSpaceMut {
+            parent: self,
+            phantom: PhantomData,
+
+            x,
+            y,
+
+            width,
+            height
+        })
+    }
+
+    /// Creates mutable slices of two regions of the space at once, such as two distant tiles
+    /// that are being swapped, which splitting the space can not always produce
+    /// If either region does not fit inside the space, or the regions share any cell, None is returned
+    #[inline]
+    pub fn disjoint_regions_mut(&mut self, a: Region, b: Region) -> Option<(SubSpaceMut<'_, T>, SubSpaceMut<'_, T>)> {
+        let bounds = self.bounds();
+
+        // The two slices may only alias the parent because no cell belongs to both
+        if !a.is_within(bounds) || !b.is_within(bounds) || a.overlaps(b) {
+            return None;
+        }
+
+        let parent: *mut Space<T> = self;
+
+        let slice = |region: Region| SubSpaceMut {
+            parent,
+            phantom: PhantomData,
+
+            x: region.x,
+            y: region.y,
+
+            width: region.width,
+            height: region.height
+        };
+
+        Some((slice(a), slice(b)))
+    }
 }
 
 impl<'a, T> SubSpaceMut<'a, T> {
@@ -62,10 +115,22 @@ impl<'a, T> SubSpaceMut<'a, T> {
         self.height
     }
 
+    /// The number of cells in the SubSpaceMut
     #[inline]
-    fn convert_coord(&self, pos_type: PostioningType, x: usize, y: usize) -> Option<(usize, usize)> {
+    pub fn len(&self) -> usize {
+        self.width * self.height
+    }
+
+    /// Whether the SubSpaceMut has no cells, which is the case when either dimension is zero
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    fn convert_coord(&self, pos_type: PositioningType, x: usize, y: usize) -> Option<(usize, usize)> {
         match pos_type {
-            PostioningType::Absolute =>  {
+            PositioningType::Absolute =>  {
                 if x < self.x 
                     || x >= self.x + self.width
                     || y < self.y
@@ -76,8 +141,8 @@ impl<'a, T> SubSpaceMut<'a, T> {
                     Some((x, y))
                 }
             }
-            PostioningType::Relative => {
-                if x > self.width || y > self.height {
+            PositioningType::Relative => {
+                if x >= self.width || y >= self.height {
                     None
                 } else {
                     Some((self.x + x, self.y + y))
@@ -86,45 +151,138 @@ impl<'a, T> SubSpaceMut<'a, T> {
         }
     }
 
+    /// Computes a pointer to the cell at an absolute position in the parent space
+    /// If the position is outside the parent space None is returned
+    ///
+    /// The pointer is computed without creating a reference to the parent space or its cells,
+    /// so references handed out by slices of disjoint regions of one space never alias
+    #[inline]
+    fn cell_ptr(&self, abs_x: usize, abs_y: usize) -> Option<*mut T> {
+        unsafe {
+            let (width, height) = ((*self.parent).width, (*self.parent).height);
+
+            if abs_x >= width || abs_y >= height {
+                return None;
+            }
+
+            let data: *mut [T] = addr_of_mut!(*(*self.parent).data);
+
+            Some(data.cast::<T>().add(abs_y * width + abs_x))
+        }
+    }
+
     /// Creates an immutable reference to a value in this slice using 
     /// the specified addressing mode
     /// If the value queried is outside the slice None will be returned
     #[inline]
-    pub fn get(&self, pos_type: PostioningType, x: usize, y: usize) -> Option<&T> {
+    pub fn get(&self, pos_type: PositioningType, x: usize, y: usize) -> Option<&T> {
         let (abs_x, abs_y) = self.convert_coord(pos_type, x, y)?;
-        
+        let cell = self.cell_ptr(abs_x, abs_y)?;
+
+        unsafe {
+            Some(&*cell)
+        }
+    }
+
+    /// Creates a mutable reference to a value in the slice using the specified addressing mode
+    /// If the value queried is outside the slice None will be returned
+    #[inline]
+    pub fn get_mut(&mut self, pos_type: PositioningType, x: usize, y: usize) -> Option<&mut T> {
+        let (abs_x, abs_y) = self.convert_coord(pos_type, x, y)?;
+        let cell = self.cell_ptr(abs_x, abs_y)?;
+
         unsafe {
-            (*self.parent).get(abs_x, abs_y)
+            Some(&mut *cell)
         }
     }
 
     /// Sets the value for the specified absolute position in the space
     /// If the position specified is outside the space false is returned
     #[inline]
-    pub fn set(&mut self, pos_type: PostioningType, x: usize, y: usize, value: T) -> bool {
-        if let Some((abs_x, abs_y)) = self.convert_coord(pos_type, x, y) {
+    pub fn set(&mut self, pos_type: PositioningType, x: usize, y: usize, value: T) -> bool {
+        let cell = self.convert_coord(pos_type, x, y)
+            .and_then(|(abs_x, abs_y)| self.cell_ptr(abs_x, abs_y));
+
+        if let Some(cell) = cell {
             unsafe {
-                (*self.parent).set(abs_x, abs_y, value)
+                *cell = value;
             }
+
+            true
         } else {
             false
         }
     }
 
+    /// Sets every cell within the thickness of the edge of this SubSpaceMut to the value,
+    /// leaving the interior untouched
+    /// A thickness of at least half of a dimension fills the whole SubSpaceMut
+    #[inline]
+    pub fn fill_border(&mut self, thickness: usize, value: T)
+        where T: Clone {
+
+        for y in 0 .. self.height {
+            for x in 0 .. self.width {
+                if in_border(x, y, self.width, self.height, thickness) {
+                    self.set(PositioningType::Relative, x, y, value.clone());
+                }
+            }
+        }
+    }
+
+    /// Tiles the pattern across this SubSpaceMut, so the cell at relative position (x, y)
+    /// becomes a clone of the pattern cell at (x % pattern width, y % pattern height)
+    /// If the pattern has no cells this SubSpaceMut is left untouched
+    #[inline]
+    pub fn fill_pattern(&mut self, pattern: &SubSpace<'_, T>)
+        where T: Clone {
+
+        let (pattern_width, pattern_height) = (pattern.width(), pattern.height());
+
+        if pattern_width == 0 || pattern_height == 0 {
+            return;
+        }
+
+        for y in 0 .. self.height {
+            for x in 0 .. self.width {
+                if let Some(value) = pattern.get(PositioningType::Relative, x % pattern_width, y % pattern_height) {
+                    self.set(PositioningType::Relative, x, y, value.clone());
+                }
+            }
+        }
+    }
+
+    /// Writes values from the iterator into the cells of this slice lexicographically,
+    /// stopping when either every cell has been written or the iterator runs out
+    /// The number of cells written is returned
+    #[inline]
+    pub fn fill_from_iter<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+        let width = self.width;
+        let positions = (0 .. self.height).flat_map(|y| (0 .. width).map(move |x| (x, y)));
+        let mut written = 0;
+
+        for ((x, y), value) in positions.zip(iter) {
+            self.set(PositioningType::Relative, x, y, value);
+            written += 1;
+        }
+
+        written
+    }
+
     /// Splits this SubSpaceMut into two new ones horizontally
     /// The left subspace contains all the points in this one that have x less than the given x_value
     /// The right subspace contains all the points in this one that have x greater than or equal to the given x_value
     #[inline]
-    pub fn split_horizontal(self, pos_type: PostioningType, x_value: usize) -> HorizontalSplit<SubSpaceMut<'a, T>> {
+    pub fn split_horizontal(self, pos_type: PositioningType, x_value: usize) -> HorizontalSplit<SubSpaceMut<'a, T>> {
         let left_x = self.x;
 
         let right_x = match pos_type {
-            PostioningType::Absolute => x_value,
-            PostioningType::Relative => self.x + x_value
+            PositioningType::Absolute => x_value,
+            PositioningType::Relative => self.x + x_value
         };
 
-        if right_x > self.width {
-            panic!("Invalid x value ({}) provided for slice with width {}", right_x, self.width);
+        if right_x < self.x || right_x > self.x + self.width {
+            panic!("Invalid x value ({}) provided for slice spanning x values {} to {}", right_x, self.x, self.x + self.width);
         }
         
         let left_width = right_x - left_x;
@@ -158,16 +316,16 @@ impl<'a, T> SubSpaceMut<'a, T> {
     /// The above subspace contains all the points in this one that have y less than the given y_value
     /// The below subspace contains all the points in this one that have y greater than or equal to the given y_value
     #[inline]
-    pub fn split_vertical(self, pos_type: PostioningType, y_value: usize) -> VerticalSplit<SubSpaceMut<'a, T>> {
+    pub fn split_vertical(self, pos_type: PositioningType, y_value: usize) -> VerticalSplit<SubSpaceMut<'a, T>> {
         let above_y = self.y;
 
         let below_y = match pos_type {
-            PostioningType::Absolute => y_value,
-            PostioningType::Relative => self.y + y_value
+            PositioningType::Absolute => y_value,
+            PositioningType::Relative => self.y + y_value
         };
 
-        if below_y > self.height {
-            panic!("Invalid y value ({}) provided for slice with height {}", below_y, self.height);
+        if below_y < self.y || below_y > self.y + self.height {
+            panic!("Invalid y value ({}) provided for slice spanning y values {} to {}", below_y, self.y, self.y + self.height);
         }
         
         let above_height = below_y - above_y;
@@ -197,6 +355,137 @@ impl<'a, T> SubSpaceMut<'a, T> {
             }
         }
     }
+
+    /// Splits this SubSpaceMut into four slices which meet at the provided point,
+    /// see `SubSpace::try_split_quadrants`
+    #[inline]
+    pub fn try_split_quadrants(self, pos_type: PositioningType, x: usize, y: usize) -> Result<Quadrants<SubSpaceMut<'a, T>>, SplitError> {
+        let region = Region::new(self.x, self.y, self.width, self.height);
+        let (x, y) = resolve_quadrants(pos_type, x, y, region)?;
+
+        let HorizontalSplit { left, right } = self.split_horizontal(PositioningType::Absolute, x);
+        let VerticalSplit { above: top_left, below: bottom_left } = left.split_vertical(PositioningType::Absolute, y);
+        let VerticalSplit { above: top_right, below: bottom_right } = right.split_vertical(PositioningType::Absolute, y);
+
+        Ok(Quadrants {
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right
+        })
+    }
+
+    /// Splits this SubSpaceMut horizontally at a fraction of its width,
+    /// see `SubSpace::split_horizontal_ratio`
+    #[inline]
+    pub fn split_horizontal_ratio(self, ratio: f32) -> HorizontalSplit<SubSpaceMut<'a, T>> {
+        let x_value = ratio_offset(ratio, self.width);
+
+        self.split_horizontal(PositioningType::Relative, x_value)
+    }
+
+    /// Splits this SubSpaceMut vertically at a fraction of its height,
+    /// see `SubSpace::split_vertical_ratio`
+    #[inline]
+    pub fn split_vertical_ratio(self, ratio: f32) -> VerticalSplit<SubSpaceMut<'a, T>> {
+        let y_value = ratio_offset(ratio, self.height);
+
+        self.split_vertical(PositioningType::Relative, y_value)
+    }
+
+    /// Divides this SubSpaceMut into non-overlapping chunks of the given dimensions,
+    /// which are yielded lexicographically by their top left corners
+    /// Chunks along the right and bottom edges shrink to fit when the
+    /// dimensions do not divide evenly
+    ///
+    /// Panics if either chunk dimension is zero
+    #[inline]
+    pub fn chunks_mut(self, chunk_width: usize, chunk_height: usize) -> SubSpaceChunksMut<'a, T> {
+        if chunk_width == 0 || chunk_height == 0 {
+            panic!("Invalid chunk dimensions ({}x{}), chunks must not be empty", chunk_width, chunk_height);
+        }
+
+        SubSpaceChunksMut {
+            parent: self.parent,
+            phantom: PhantomData,
+
+            x: self.x,
+            y: self.y,
+            width: self.width,
+            height: self.height,
+
+            chunk_width,
+            chunk_height,
+
+            offset_x: 0,
+            offset_y: 0
+        }
+    }
+}
+
+/// An iterator over the non-overlapping chunks of a SubSpaceMut
+pub struct SubSpaceChunksMut<'a, T> {
+    /// The space that the SubSpaceMut is from
+    parent: *mut Space<T>,
+
+    /// Assists the borrow checker in preventing race conditions
+    phantom: PhantomData<&'a mut Space<T>>,
+
+    /// The X position that the SubSpaceMut starts at
+    x: usize,
+
+    /// The Y position that the SubSpaceMut starts at
+    y: usize,
+
+    /// The width (X direction) of the SubSpaceMut
+    width: usize,
+
+    /// The height (Y direction) of the SubSpaceMut
+    height: usize,
+
+    /// The width (X direction) of a chunk that does not touch the right edge
+    chunk_width: usize,
+
+    /// The height (Y direction) of a chunk that does not touch the bottom edge
+    chunk_height: usize,
+
+    /// The relative X position of the next chunk
+    offset_x: usize,
+
+    /// The relative Y position of the next chunk
+    offset_y: usize
+}
+
+impl<'a, T> Iterator for SubSpaceChunksMut<'a, T> {
+    type Item = SubSpaceMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset_x >= self.width {
+            self.offset_x = 0;
+            self.offset_y += self.chunk_height;
+        }
+
+        if self.offset_y >= self.height || self.width == 0 {
+            return None;
+        }
+
+        // Every chunk covers a distinct rectangle of the SubSpaceMut,
+        // so no two of the views handed out can alias each other
+        let chunk = SubSpaceMut {
+            parent: self.parent,
+            phantom: PhantomData,
+
+            x: self.x + self.offset_x,
+            width: self.chunk_width.min(self.width - self.offset_x),
+
+            y: self.y + self.offset_y,
+            height: self.chunk_height.min(self.height - self.offset_y)
+        };
+
+        self.offset_x += self.chunk_width;
+
+        Some(chunk)
+    }
 }
 
 #[cfg(test)]
@@ -208,7 +497,7 @@ mod tests {
         let mut space = Space::new_flat(1u32, 4, 4);
         let space_slice = space.as_subspace_mut();
 
-        let HorizontalSplit { left, right } = space_slice.split_horizontal(PostioningType::Absolute, 2);
+        let HorizontalSplit { left, right } = space_slice.split_horizontal(PositioningType::Absolute, 2);
 
         assert_eq!(left.width(), 2);
         assert_eq!(right.width(), 2);
@@ -219,9 +508,280 @@ mod tests {
         let mut space = Space::new_flat(1u32, 4, 4);
         let space_slice = space.as_subspace_mut();
 
-        let VerticalSplit { above, below } = space_slice.split_vertical(PostioningType::Absolute, 2);
+        let VerticalSplit { above, below } = space_slice.split_vertical(PositioningType::Absolute, 2);
 
         assert_eq!(above.height(), 2);
         assert_eq!(below.height(), 2);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn split_ratio_test() {
+        let mut space = Space::new_flat(0u8, 10, 3);
+
+        let HorizontalSplit { mut left, right } = space.as_subspace_mut().split_horizontal_ratio(0.3);
+
+        assert_eq!((left.width(), right.width()), (3, 7));
+        assert!(left.set(PositioningType::Relative, 2, 0, 1));
+
+        let VerticalSplit { above, below } = right.split_vertical_ratio(0.0);
+
+        assert_eq!((above.height(), below.height()), (0, 3));
+        assert_eq!(space.iter().filter(|v| **v == 1).count(), 1);
+    }
+
+    #[test]
+    fn get_mut_test() {
+        let mut space: Space<Vec<i32>> = Space::new_flat(Vec::new(), 4, 4);
+
+        {
+            let HorizontalSplit { mut right, .. } = space.as_subspace_mut().split_horizontal(PositioningType::Absolute, 2);
+
+            right.get_mut(PositioningType::Relative, 1, 3).unwrap().push(7);
+            right.get_mut(PositioningType::Absolute, 3, 3).unwrap().push(8);
+
+            assert!(right.get_mut(PositioningType::Absolute, 1, 3).is_none());
+            assert!(right.get_mut(PositioningType::Relative, 2, 0).is_none());
+        }
+
+        assert_eq!(space.get(3, 3), Some(&vec![7, 8]));
+        assert_eq!(space.iter().filter(|cell| !cell.is_empty()).count(), 1);
+    }
+
+    #[test]
+    fn try_split_quadrants_test() {
+        let mut space = Space::new_flat(0u8, 4, 4);
+
+        let error = space.as_subspace_mut().try_split_quadrants(PositioningType::Relative, 5, 1).err();
+
+        assert!(matches!(error, Some(SplitError::Horizontal { x: 5, .. })));
+
+        {
+            let Quadrants { mut top_left, mut bottom_right, .. } = space.as_subspace_mut().try_split_quadrants(PositioningType::Absolute, 2, 3).unwrap();
+
+            assert!(top_left.set(PositioningType::Relative, 1, 2, 1));
+            assert!(bottom_right.set(PositioningType::Relative, 1, 0, 2));
+            assert!(!bottom_right.set(PositioningType::Relative, 0, 1, 2));
+        }
+
+        assert_eq!(space.get(1, 2), Some(&1));
+        assert_eq!(space.get(3, 3), Some(&2));
+    }
+
+    #[test]
+    fn fill_from_iter_test() {
+        let mut space = Space::new_flat(0, 4, 4);
+
+        {
+            let mut subspace = space.as_subspace_mut_at(1, 2, 2, 2).unwrap();
+
+            assert_eq!(subspace.fill_from_iter(vec![1, 2, 3]), 3);
+        }
+
+        assert_eq!((space.get(1, 2), space.get(2, 2), space.get(1, 3), space.get(2, 3)), (Some(&1), Some(&2), Some(&3), Some(&0)));
+
+        let mut subspace = space.as_subspace_mut_at(0, 0, 2, 1).unwrap();
+
+        assert_eq!(subspace.fill_from_iter(5 ..), 2);
+    }
+
+    #[test]
+    fn disjoint_regions_mut_test() {
+        let mut space = Space::new_mapped(|x, y| (y * 6 + x) as i32, 6, 4);
+
+        {
+            let (mut first, mut second) = space.disjoint_regions_mut(Region::new(0, 0, 2, 2), Region::new(4, 2, 2, 2)).unwrap();
+
+            for y in 0 .. 2 {
+                for x in 0 .. 2 {
+                    let a = first.get_mut(PositioningType::Relative, x, y).unwrap();
+                    let b = second.get_mut(PositioningType::Relative, x, y).unwrap();
+
+                    std::mem::swap(a, b);
+                }
+            }
+        }
+
+        assert_eq!(space.get(0, 0), Some(&16));
+        assert_eq!(space.get(1, 1), Some(&23));
+        assert_eq!(space.get(4, 2), Some(&0));
+        assert_eq!(space.get(5, 3), Some(&7));
+        assert_eq!(space.get(2, 0), Some(&2));
+
+        // Regions that touch along an edge or at a corner share no cells
+        assert!(space.disjoint_regions_mut(Region::new(0, 0, 3, 2), Region::new(3, 0, 3, 2)).is_some());
+        assert!(space.disjoint_regions_mut(Region::new(0, 0, 3, 2), Region::new(0, 2, 3, 2)).is_some());
+        assert!(space.disjoint_regions_mut(Region::new(0, 0, 3, 2), Region::new(3, 2, 3, 2)).is_some());
+
+        // Regions without cells never overlap
+        assert!(space.disjoint_regions_mut(Region::new(1, 1, 0, 2), Region::new(0, 0, 3, 3)).is_some());
+    }
+
+    #[test]
+    fn disjoint_regions_mut_overlap_test() {
+        let mut space = Space::new_flat(0u8, 6, 4);
+
+        let overlapping = [
+            // Identical regions
+            (Region::new(1, 1, 2, 2), Region::new(1, 1, 2, 2)),
+            // Sharing a single corner cell
+            (Region::new(0, 0, 2, 2), Region::new(1, 1, 2, 2)),
+            // One region inside the other
+            (Region::new(0, 0, 6, 4), Region::new(2, 1, 1, 1)),
+            (Region::new(2, 1, 1, 1), Region::new(0, 0, 6, 4)),
+            // Crossing without containing a corner of each other
+            (Region::new(2, 0, 1, 4), Region::new(0, 2, 6, 1)),
+            // Sharing the last cell of a column
+            (Region::new(0, 0, 3, 4), Region::new(2, 3, 1, 1))
+        ];
+
+        for (a, b) in overlapping.iter().copied() {
+            assert!(space.disjoint_regions_mut(a, b).is_none(), "{:?} and {:?} overlap", a, b);
+        }
+
+        let escaping = [
+            (Region::new(5, 0, 2, 1), Region::new(0, 2, 1, 1)),
+            (Region::new(0, 0, 1, 1), Region::new(0, 3, 1, 2)),
+            (Region::new(0, 0, 1, 1), Region::new(usize::MAX, 0, 2, 1))
+        ];
+
+        for (a, b) in escaping.iter().copied() {
+            assert!(space.disjoint_regions_mut(a, b).is_none());
+            assert!(space.disjoint_regions_mut(b, a).is_none());
+        }
+    }
+
+    #[test]
+    fn len_test() {
+        let mut space = Space::new_flat(0u8, 5, 4);
+        let subspace = space.as_subspace_mut();
+
+        let VerticalSplit { above, below } = subspace.split_vertical(PositioningType::Absolute, 1);
+
+        assert_eq!(above.len(), 5);
+        assert_eq!(below.len(), 15);
+        assert!(!below.is_empty());
+
+        let mut empty = Space::new_flat(0u8, 0, 5);
+
+        assert!(empty.as_subspace_mut().is_empty());
+    }
+
+    #[test]
+    fn as_subspace_mut_at_test() {
+        let mut space = Space::new_flat(0u8, 4, 4);
+
+        let mut view = space.as_subspace_mut_at(2, 1, 2, 2).unwrap();
+
+        assert!(view.set(PositioningType::Relative, 1, 1, 5));
+        assert!(!view.set(PositioningType::Relative, 2, 0, 5));
+
+        assert_eq!(*space.get(3, 2).unwrap(), 5);
+        assert_eq!(space.iter().filter(|v| **v == 5).count(), 1);
+        assert!(space.as_subspace_mut_at(2, 1, 3, 2).is_none());
+    }
+
+    #[test]
+    fn nested_split_test() {
+        let mut space = Space::new_flat(0u8, 10, 10);
+        let subspace = space.as_subspace_mut();
+
+        let VerticalSplit { below, .. } = subspace.split_vertical(PositioningType::Absolute, 5);
+        let VerticalSplit { above, mut below } = below.split_vertical(PositioningType::Relative, 3);
+
+        assert_eq!((above.height(), below.height()), (3, 2));
+        assert!(below.set(PositioningType::Relative, 0, 0, 1));
+        assert_eq!(*space.get(0, 8).unwrap(), 1);
+    }
+
+    #[test]
+    fn positioning_type_reexport_test() {
+        let mut space = Space::new_mapped(|x, y| (y * 4 + x) as u8, 4, 4);
+
+        // The crate root re-exports the single set of types defined in primitives,
+        // so a value named through one path is accepted wherever the other is expected
+        let absolute: crate::PositioningType = crate::primitives::PositioningType::Absolute;
+
+        let crate::HorizontalSplit { left, .. } = space.as_subspace().split_horizontal(absolute, 2);
+        let split: crate::primitives::VerticalSplit<SubSpace<'_, u8>> = left.split_vertical(crate::PositioningType::Relative, 1);
+
+        assert_eq!((split.above.width(), split.above.height()), (2, 1));
+        assert_eq!(split.below.get(crate::primitives::PositioningType::Relative, 1, 0), Some(&5));
+
+        let absolute: crate::primitives::PositioningType = crate::PositioningType::Absolute;
+        let crate::HorizontalSplit { mut right, .. } = space.as_subspace_mut().split_horizontal(absolute, 2);
+
+        assert!(right.set(crate::PositioningType::Relative, 0, 0, 99));
+        assert_eq!(space.get(2, 0), Some(&99));
+    }
+
+    #[test]
+    fn fill_border_test() {
+        let mut space = Space::new_flat(0u8, 6, 4);
+        let subspace = space.as_subspace_mut();
+
+        let HorizontalSplit { mut right, .. } = subspace.split_horizontal(PositioningType::Absolute, 3);
+
+        right.fill_border(1, 1);
+
+        // The left half is untouched and the interior of the right half is 1x2
+        assert_eq!(space.iter().filter(|v| **v == 1).count(), 10);
+        assert_eq!(*space.get(2, 1).unwrap(), 0);
+        assert_eq!(*space.get(3, 1).unwrap(), 1);
+        assert_eq!(*space.get(4, 1).unwrap(), 0);
+        assert_eq!(*space.get(4, 2).unwrap(), 0);
+    }
+
+    #[test]
+    fn fill_pattern_test() {
+        let pattern = Space::new_mapped(|x, y| y * 2 + x, 2, 2);
+        let mut space = Space::new_flat(9, 5, 5);
+
+        space.as_subspace_mut().fill_pattern(&pattern.as_subspace());
+
+        for y in 0 .. 5 {
+            for x in 0 .. 5 {
+                assert_eq!(*space.get(x, y).unwrap(), (y % 2) * 2 + x % 2);
+            }
+        }
+
+        let empty = Space::new_flat(0, 0, 3);
+        let before = space.clone();
+
+        space.as_subspace_mut().fill_pattern(&empty.as_subspace());
+
+        assert_eq!(space, before);
+    }
+
+    #[test]
+    fn chunks_mut_test() {
+        let mut space = Space::new_flat(0usize, 6, 6);
+
+        for (index, mut chunk) in space.as_subspace_mut().chunks_mut(3, 3).enumerate() {
+            assert_eq!((chunk.width(), chunk.height()), (3, 3));
+
+            for y in 0 .. 3 {
+                for x in 0 .. 3 {
+                    chunk.set(PositioningType::Relative, x, y, index + 1);
+                }
+            }
+        }
+
+        for y in 0 .. 6 {
+            for x in 0 .. 6 {
+                assert_eq!(*space.get(x, y).unwrap(), (y / 3) * 2 + x / 3 + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn chunks_mut_edge_test() {
+        let mut space = Space::new_flat(0u8, 7, 5);
+
+        let dimensions = space.as_subspace_mut()
+            .chunks_mut(3, 3)
+            .map(|chunk| (chunk.width(), chunk.height()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(dimensions, vec![(3, 3), (3, 3), (1, 3), (3, 2), (3, 2), (1, 2)]);
+    }
+}
@@ -0,0 +1,334 @@
+use std::mem::MaybeUninit;
+
+use crate::{Space, assume_init_buffer};
+use crate::primitives::*;
+
+/// The side length of the square tiles used by the blocked transpose,
+/// a tile of reads and a tile of writes comfortably fit in cache together
+const TRANSPOSE_BLOCK: usize = 32;
+
+impl<T> Space<T> {
+    /// Creates a new space with the provided dimensions where each cell is a clone
+    /// of the cell in this space at the position returned by the source function
+    #[inline]
+    fn remap<F>(&self, width: usize, height: usize, source: F) -> Space<T>
+        where
+            T: Clone,
+            F: Fn(usize, usize) -> (usize, usize) {
+
+        let mut vec = Vec::with_capacity(width * height);
+
+        for y in 0 .. height {
+            for x in 0 .. width {
+                let (source_x, source_y) = source(x, y);
+
+                vec.push(self.data[source_y * self.width + source_x].clone());
+            }
+        }
+
+        Space {
+            data: vec.into_boxed_slice(),
+            width,
+            height
+        }
+    }
+
+    /// Creates a new space which is this one rotated clockwise,
+    /// the width and height are swapped for quarter and three quarter turns
+    #[inline]
+    pub fn rotate(&self, rotation: Rotation) -> Space<T>
+        where T: Clone {
+
+        let (width, height) = (self.width, self.height);
+
+        match rotation {
+            Rotation::None => self.clone(),
+            Rotation::Cw90 => self.remap(height, width, |x, y| (y, height - 1 - x)),
+            Rotation::Cw180 => self.remap(width, height, |x, y| (width - 1 - x, height - 1 - y)),
+            Rotation::Cw270 => self.remap(height, width, |x, y| (width - 1 - y, x))
+        }
+    }
+
+    /// Creates a new space which is this one transformed by the orientation,
+    /// the width and height are swapped for the orientations that turn or reflect diagonally
+    ///
+    /// Every orientation is a single pass over the result, rather than a rotation followed by a flip
+    #[inline]
+    pub fn oriented(&self, orientation: Orientation) -> Space<T>
+        where T: Clone {
+
+        let (width, height) = (self.width, self.height);
+
+        match orientation {
+            Orientation::Identity => self.clone(),
+            Orientation::MirrorHorizontal => self.remap(width, height, |x, y| (width - 1 - x, y)),
+            Orientation::Rotate180 => self.remap(width, height, |x, y| (width - 1 - x, height - 1 - y)),
+            Orientation::MirrorVertical => self.remap(width, height, |x, y| (x, height - 1 - y)),
+            Orientation::Transpose => self.remap(height, width, |x, y| (y, x)),
+            Orientation::Rotate90 => self.remap(height, width, |x, y| (y, height - 1 - x)),
+            Orientation::Transverse => self.remap(height, width, |x, y| (width - 1 - y, height - 1 - x)),
+            Orientation::Rotate270 => self.remap(height, width, |x, y| (width - 1 - y, x))
+        }
+    }
+
+    /// Creates a new space which is this one reflected across its main diagonal,
+    /// so the cell at (x, y) in the result is the cell at (y, x) in this space
+    /// and the width and height are swapped
+    ///
+    /// The cells are copied in square tiles so that both the reads and the writes
+    /// stay local, which is much faster than a naive transpose for large spaces
+    #[inline]
+    pub fn transpose(&self) -> Space<T>
+        where T: Clone {
+
+        let (width, height) = (self.height, self.width);
+
+        let mut buffer: Vec<MaybeUninit<T>> = Vec::with_capacity(width * height);
+        buffer.resize_with(width * height, MaybeUninit::uninit);
+
+        for block_y in (0 .. height).step_by(TRANSPOSE_BLOCK) {
+            for block_x in (0 .. width).step_by(TRANSPOSE_BLOCK) {
+                for y in block_y .. (block_y + TRANSPOSE_BLOCK).min(height) {
+                    for x in block_x .. (block_x + TRANSPOSE_BLOCK).min(width) {
+                        buffer[y * width + x].write(self.data[x * self.width + y].clone());
+                    }
+                }
+            }
+        }
+
+        // The tiles cover every cell of the buffer exactly once
+        Space {
+            data: unsafe { assume_init_buffer(buffer) },
+            width,
+            height
+        }
+    }
+
+    /// Reflects a square space across its main diagonal in place
+    /// If the space is not square it is left untouched and false is returned
+    #[inline]
+    pub fn transpose_in_place(&mut self) -> bool {
+        if self.width != self.height {
+            return false;
+        }
+
+        let side = self.width;
+
+        for y in 0 .. side {
+            for x in y + 1 .. side {
+                self.data.swap(y * side + x, x * side + y);
+            }
+        }
+
+        true
+    }
+
+    /// Creates a new space which is this one reflected across its anti-diagonal,
+    /// so the cell at (x, y) in the result is the cell at (height - 1 - y, width - 1 - x),
+    /// measured with the dimensions of the result, and the width and height are swapped
+    #[inline]
+    pub fn mirror_anti_diagonal(&self) -> Space<T>
+        where T: Clone {
+
+        let (width, height) = (self.width, self.height);
+
+        self.remap(height, width, |x, y| (width - 1 - y, height - 1 - x))
+    }
+
+    /// Reflects a square space across its anti-diagonal in place
+    /// If the space is not square it is left untouched and false is returned
+    #[inline]
+    pub fn mirror_anti_diagonal_in_place(&mut self) -> bool {
+        if self.width != self.height {
+            return false;
+        }
+
+        let side = self.width;
+
+        for y in 0 .. side {
+            for x in 0 .. side.saturating_sub(y + 1) {
+                self.data.swap(y * side + x, (side - 1 - x) * side + (side - 1 - y));
+            }
+        }
+
+        true
+    }
+}
+
+impl<T> Space<T>
+    where T: PartialEq {
+
+    /// Checks whether the space is unchanged when mirrored left to right,
+    /// so every cell (x, y) equals the cell (width - 1 - x, y)
+    #[inline]
+    pub fn is_horizontally_symmetric(&self) -> bool {
+        self.data.chunks_exact(self.width.max(1))
+            .all(|row| row.iter().zip(row.iter().rev()).take(row.len() / 2).all(|(a, b)| a == b))
+    }
+
+    /// Checks whether the space is unchanged when mirrored top to bottom,
+    /// so every cell (x, y) equals the cell (x, height - 1 - y)
+    #[inline]
+    pub fn is_vertically_symmetric(&self) -> bool {
+        let width = self.width;
+
+        (0 .. self.height / 2).all(|y| {
+            let mirrored = self.height - 1 - y;
+
+            self.data[y * width .. (y + 1) * width] == self.data[mirrored * width .. (mirrored + 1) * width]
+        })
+    }
+
+    /// Checks whether the space is unchanged when rotated by a half turn,
+    /// so every cell (x, y) equals the cell (width - 1 - x, height - 1 - y)
+    #[inline]
+    pub fn is_rotationally_symmetric_180(&self) -> bool {
+        // A half turn reverses the order of the linear memory
+        self.data.iter()
+            .zip(self.data.iter().rev())
+            .take(self.data.len() / 2)
+            .all(|(a, b)| a == b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_transpose<T: Clone>(space: &Space<T>) -> Space<T> {
+        let mut iter = (0 .. space.width())
+            .flat_map(|x| (0 .. space.height()).map(move |y| (x, y)))
+            .map(|(x, y)| space.get(x, y).unwrap().clone());
+
+        Space::from_iter(&mut iter, space.height(), space.width()).unwrap()
+    }
+
+    #[test]
+    fn rotate_test() {
+        // 0 1
+        // 2 3
+        // 4 5
+        let space = Space::new_mapped(|x, y| y * 2 + x, 2, 3);
+
+        assert_eq!(space.rotate(Rotation::None), space);
+
+        let cw90 = space.rotate(Rotation::Cw90);
+        assert_eq!((cw90.width(), cw90.height()), (3, 2));
+        assert_eq!(cw90.iter().copied().collect::<Vec<_>>(), vec![4, 2, 0, 5, 3, 1]);
+
+        let cw180 = space.rotate(Rotation::Cw180);
+        assert_eq!((cw180.width(), cw180.height()), (2, 3));
+        assert_eq!(cw180.iter().copied().collect::<Vec<_>>(), vec![5, 4, 3, 2, 1, 0]);
+
+        let cw270 = space.rotate(Rotation::Cw270);
+        assert_eq!((cw270.width(), cw270.height()), (3, 2));
+        assert_eq!(cw270.iter().copied().collect::<Vec<_>>(), vec![1, 3, 5, 0, 2, 4]);
+    }
+
+    #[test]
+    fn oriented_test() {
+        // 0 1
+        // 2 3
+        // 4 5
+        let space = Space::new_mapped(|x, y| y * 2 + x, 2, 3);
+
+        let cells = |orientation: u8| {
+            let oriented = space.oriented(Orientation::from_exif(orientation).unwrap());
+
+            assert_eq!(Orientation::from_exif(orientation).unwrap().swaps_dimensions(), oriented.width() == 3);
+
+            oriented.iter().copied().collect::<Vec<_>>()
+        };
+
+        assert_eq!(cells(1), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(cells(2), vec![1, 0, 3, 2, 5, 4]);
+        assert_eq!(cells(3), vec![5, 4, 3, 2, 1, 0]);
+        assert_eq!(cells(4), vec![4, 5, 2, 3, 0, 1]);
+        assert_eq!(cells(5), vec![0, 2, 4, 1, 3, 5]);
+        assert_eq!(cells(7), vec![5, 3, 1, 4, 2, 0]);
+
+        // Orientation 6 moves the bottom left corner to the top left
+        let rotated = space.oriented(Orientation::Rotate90);
+
+        assert_eq!((rotated.get(0, 0), rotated.get(2, 0), rotated.get(0, 1), rotated.get(2, 1)), (Some(&4), Some(&0), Some(&5), Some(&1)));
+        assert_eq!(rotated, space.rotate(Rotation::Cw90));
+        assert_eq!(space.oriented(Orientation::Rotate270), space.rotate(Rotation::Cw270));
+
+        assert_eq!(Orientation::from_exif(9), None);
+    }
+
+    #[test]
+    fn diagonal_mirror_test() {
+        // 0 1 2
+        // 3 4 5
+        // 6 7 8
+        let space = Space::new_mapped(|x, y| y * 3 + x, 3, 3);
+
+        let transposed = space.transpose();
+        assert_eq!(transposed.iter().copied().collect::<Vec<_>>(), vec![0, 3, 6, 1, 4, 7, 2, 5, 8]);
+
+        let anti = space.mirror_anti_diagonal();
+        assert_eq!(anti.iter().copied().collect::<Vec<_>>(), vec![8, 5, 2, 7, 4, 1, 6, 3, 0]);
+
+        let mut in_place = space.clone();
+        assert!(in_place.transpose_in_place());
+        assert_eq!(in_place, transposed);
+
+        let mut in_place = space.clone();
+        assert!(in_place.mirror_anti_diagonal_in_place());
+        assert_eq!(in_place, anti);
+
+        let mut non_square = Space::new_mapped(|x, y| y * 2 + x, 2, 3);
+        assert!(!non_square.mirror_anti_diagonal_in_place());
+        assert_eq!(*non_square.mirror_anti_diagonal().get(0, 0).unwrap(), 5);
+    }
+
+    #[test]
+    fn symmetric_test() {
+        // 1 2 1
+        // 3 4 3
+        // 1 2 1
+        let space = Space::new_mapped(|x, y| [1, 2, 1, 3, 4, 3, 1, 2, 1][y * 3 + x], 3, 3);
+
+        assert!(space.is_horizontally_symmetric());
+        assert!(space.is_vertically_symmetric());
+        assert!(space.is_rotationally_symmetric_180());
+    }
+
+    #[test]
+    fn asymmetric_test() {
+        // 1 2 3
+        // 4 5 4
+        // 3 2 1
+        let space = Space::new_mapped(|x, y| [1, 2, 3, 4, 5, 4, 3, 2, 1][y * 3 + x], 3, 3);
+
+        assert!(!space.is_horizontally_symmetric());
+        assert!(!space.is_vertically_symmetric());
+        assert!(space.is_rotationally_symmetric_180());
+
+        let lopsided = Space::new_mapped(|x, y| x * y, 4, 2);
+
+        assert!(!lopsided.is_rotationally_symmetric_180());
+    }
+
+    #[test]
+    fn transpose_non_square_test() {
+        let space = Space::new_mapped(|x, y| (x, y), 70, 45);
+
+        let transposed = space.transpose();
+
+        assert_eq!(transposed.width(), 45);
+        assert_eq!(transposed.height(), 70);
+        assert_eq!(*transposed.get(44, 69).unwrap(), (69, 44));
+        assert_eq!(transposed, naive_transpose(&space));
+    }
+
+    #[test]
+    fn transpose_large_test() {
+        let space = Space::new_mapped(|x, y| (x * 3_000 + y) as u32, 2_000, 1_500);
+
+        let transposed = space.transpose();
+
+        assert_eq!(transposed.transpose(), space);
+    }
+}
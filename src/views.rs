@@ -0,0 +1,548 @@
+use crate::{Space, SubSpace};
+use crate::primitives::*;
+
+/// A read-only view of a SubSpace that treats it as a torus,
+/// so every coordinate wraps around to a cell inside the SubSpace
+#[derive(Debug)]
+pub struct WrappedView<'a, T> {
+    /// The SubSpace that this view wraps
+    inner: SubSpace<'a, T>
+}
+
+impl<'a, T> Clone for WrappedView<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        WrappedView {
+            inner: self.inner.clone()
+        }
+    }
+}
+
+/// A read-only view of every step_x-th column and step_y-th row of a SubSpace,
+/// starting from its top left cell, which gives a thumbnail without copying
+#[derive(Debug)]
+pub struct StridedView<'a, T> {
+    /// The SubSpace that this view samples
+    inner: SubSpace<'a, T>,
+
+    /// The distance between two sampled columns
+    step_x: usize,
+
+    /// The distance between two sampled rows
+    step_y: usize
+}
+
+impl<'a, T> Clone for StridedView<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        StridedView {
+            inner: self.inner.clone(),
+
+            step_x: self.step_x,
+            step_y: self.step_y
+        }
+    }
+}
+
+/// A read-only view of a SubSpace in any of its eight orientations,
+/// built by composing transposes and flips without copying any cells
+#[derive(Debug)]
+pub struct OrientedView<'a, T> {
+    /// The SubSpace that this view reorients
+    inner: SubSpace<'a, T>,
+
+    /// Whether the X and Y axes of the view are swapped relative to the SubSpace
+    transposed: bool,
+
+    /// Whether the X axis of the SubSpace is read backwards
+    flip_x: bool,
+
+    /// Whether the Y axis of the SubSpace is read backwards
+    flip_y: bool
+}
+
+impl<'a, T> Clone for OrientedView<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        OrientedView {
+            inner: self.inner.clone(),
+
+            transposed: self.transposed,
+            flip_x: self.flip_x,
+            flip_y: self.flip_y
+        }
+    }
+}
+
+/// A snapshot of a space stored column by column, so that every column is contiguous
+/// This trades a copy of the space for fast repeated column reads, and does not
+/// reflect any changes made to the space after it was created
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ColumnView<T> {
+    /// The transpose of the space, whose rows are the columns of the space
+    columns: Space<T>
+}
+
+impl<T> Space<T> {
+    /// Creates a snapshot of this space where each column is stored contiguously
+    #[inline]
+    pub fn column_optimized(&self) -> ColumnView<T>
+        where T: Clone {
+
+        ColumnView {
+            columns: self.transpose()
+        }
+    }
+
+    /// Creates a view of this space transformed by the orientation without copying any cells,
+    /// the width and height of the view are swapped for the orientations that turn or reflect diagonally
+    /// The view reads the same cells as `oriented` would copy
+    #[inline]
+    pub fn oriented_view(&self, orientation: Orientation) -> OrientedView<'_, T> {
+        // Whether the axes are swapped, then whether each axis of the space is read backwards
+        let (transposed, flip_x, flip_y) = match orientation {
+            Orientation::Identity => (false, false, false),
+            Orientation::MirrorHorizontal => (false, true, false),
+            Orientation::Rotate180 => (false, true, true),
+            Orientation::MirrorVertical => (false, false, true),
+            Orientation::Transpose => (true, false, false),
+            Orientation::Rotate90 => (true, false, true),
+            Orientation::Transverse => (true, true, true),
+            Orientation::Rotate270 => (true, true, false)
+        };
+
+        OrientedView {
+            inner: self.as_subspace(),
+
+            transposed,
+            flip_x,
+            flip_y
+        }
+    }
+}
+
+impl<T> ColumnView<T> {
+    /// The width (X direction) of the space this snapshot was taken of
+    #[inline]
+    pub fn width(&self) -> usize {
+        // The snapshot is transposed, so its dimensions are swapped
+        self.columns.height()
+    }
+
+    /// The height (Y direction) of the space this snapshot was taken of
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.columns.width()
+    }
+
+    /// The cells of a column from top to bottom
+    /// If the column is outside the space None is returned
+    #[inline]
+    pub fn column(&self, x: usize) -> Option<&[T]> {
+        if x >= self.width() {
+            return None;
+        }
+
+        let height = self.height();
+
+        Some(&self.columns.data[x * height .. (x + 1) * height])
+    }
+}
+
+impl<'a, T> SubSpace<'a, T> {
+    /// Creates a view of this SubSpace where every coordinate wraps around
+    /// modulo the dimensions, which suits algorithms with periodic boundaries
+    #[inline]
+    pub fn wrapped(&self) -> WrappedView<'a, T> {
+        WrappedView {
+            inner: self.clone()
+        }
+    }
+
+    /// Creates a view of every step_x-th column and step_y-th row of this SubSpace,
+    /// so the cell at (x, y) in the view is the cell at (x * step_x, y * step_y) in this SubSpace
+    /// Partial steps at the right and bottom edges are kept, so the view is
+    /// width / step_x by height / step_y cells, rounded up
+    ///
+    /// Panics if either step is zero
+    #[inline]
+    pub fn subsample(&self, step_x: usize, step_y: usize) -> StridedView<'a, T> {
+        if step_x == 0 || step_y == 0 {
+            panic!("Invalid steps ({}, {}) provided, steps must be non-zero", step_x, step_y);
+        }
+
+        StridedView {
+            inner: self.clone(),
+
+            step_x,
+            step_y
+        }
+    }
+
+    /// Creates a view of this SubSpace in its original orientation,
+    /// which can then be reoriented by the methods of the view
+    #[inline]
+    pub fn oriented(&self) -> OrientedView<'a, T> {
+        OrientedView {
+            inner: self.clone(),
+
+            transposed: false,
+            flip_x: false,
+            flip_y: false
+        }
+    }
+
+    /// Creates a view of this SubSpace reflected across its main diagonal,
+    /// see `OrientedView::transpose_view`
+    #[inline]
+    pub fn transpose_view(&self) -> OrientedView<'a, T> {
+        self.oriented().transpose_view()
+    }
+
+    /// Creates a view of this SubSpace mirrored left to right,
+    /// see `OrientedView::flip_horizontal`
+    #[inline]
+    pub fn flip_horizontal(&self) -> OrientedView<'a, T> {
+        self.oriented().flip_horizontal()
+    }
+
+    /// Creates a view of this SubSpace mirrored top to bottom,
+    /// see `OrientedView::flip_vertical`
+    #[inline]
+    pub fn flip_vertical(&self) -> OrientedView<'a, T> {
+        self.oriented().flip_vertical()
+    }
+
+    /// Creates a view of this SubSpace reflected across its main diagonal,
+    /// see `OrientedView::flip_main_diagonal`
+    #[inline]
+    pub fn flip_main_diagonal(&self) -> OrientedView<'a, T> {
+        self.oriented().flip_main_diagonal()
+    }
+
+    /// Creates a view of this SubSpace reflected across its anti-diagonal,
+    /// see `OrientedView::flip_anti_diagonal`
+    #[inline]
+    pub fn flip_anti_diagonal(&self) -> OrientedView<'a, T> {
+        self.oriented().flip_anti_diagonal()
+    }
+
+    /// Creates an iterator over a window around every cell of this SubSpace, where
+    /// coordinates outside the SubSpace wrap around to the opposite edge,
+    /// so unlike a bounded window every cell produces a full window
+    /// The windows are yielded lexicographically by their center cell and each one
+    /// holds its cells lexicographically, with the center at (window width / 2, window height / 2)
+    #[inline]
+    pub fn wrapped_windows(&self, window_width: usize, window_height: usize) -> impl Iterator<Item = Vec<&'a T>> {
+        let wrapped = self.wrapped();
+        let (width, height) = (self.width(), self.height());
+
+        (0 .. height)
+            .flat_map(move |y| (0 .. width).map(move |x| (x, y)))
+            .map(move |(x, y)| {
+                let left = x as isize - (window_width / 2) as isize;
+                let top = y as isize - (window_height / 2) as isize;
+
+                (top .. top + window_height as isize)
+                    .flat_map(|sample_y| (left .. left + window_width as isize).map(move |sample_x| (sample_x, sample_y)))
+                    .filter_map(|(sample_x, sample_y)| wrapped.get_signed(sample_x, sample_y))
+                    .collect()
+            })
+    }
+}
+
+impl<'a, T> WrappedView<'a, T> {
+    /// The width (X direction) of the wrapped SubSpace
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.inner.width()
+    }
+
+    /// The height (Y direction) of the wrapped SubSpace
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.inner.height()
+    }
+
+    /// Creates an immutable reference to the value at a relative position,
+    /// after wrapping the position into the SubSpace
+    /// None is only returned when the SubSpace has no cells
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        if self.inner.is_empty() {
+            return None;
+        }
+
+        self.inner.get(PositioningType::Relative, x % self.width(), y % self.height())
+    }
+
+    /// Creates an immutable reference to the value at a relative position
+    /// which may be negative, after wrapping the position into the SubSpace
+    /// None is only returned when the SubSpace has no cells
+    #[inline]
+    pub fn get_signed(&self, x: isize, y: isize) -> Option<&'a T> {
+        let (x, y) = BorderMode::Wrapping.resolve(x, y, self.width(), self.height())?;
+
+        self.inner.get(PositioningType::Relative, x, y)
+    }
+}
+
+impl<'a, T> StridedView<'a, T> {
+    /// The width (X direction) of the view
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.inner.width().div_ceil(self.step_x)
+    }
+
+    /// The height (Y direction) of the view
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.inner.height().div_ceil(self.step_y)
+    }
+
+    /// Creates an immutable reference to the value at a position in the view
+    /// If the position is outside the view None is returned
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        if x >= self.width() || y >= self.height() {
+            return None;
+        }
+
+        self.inner.get(PositioningType::Relative, x * self.step_x, y * self.step_y)
+    }
+
+    /// Creates an iterator that reads through the view lexicographically
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let view = self.clone();
+        let width = self.width();
+
+        (0 .. self.height())
+            .flat_map(move |y| (0 .. width).map(move |x| (x, y)))
+            .filter_map(move |(x, y)| view.get(x, y))
+    }
+}
+
+impl<'a, T> OrientedView<'a, T> {
+    /// The width (X direction) of the view
+    #[inline]
+    pub fn width(&self) -> usize {
+        if self.transposed { self.inner.height() } else { self.inner.width() }
+    }
+
+    /// The height (Y direction) of the view
+    #[inline]
+    pub fn height(&self) -> usize {
+        if self.transposed { self.inner.width() } else { self.inner.height() }
+    }
+
+    /// Creates an immutable reference to the value at a position in the view
+    /// If the position is outside the view None is returned
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        if x >= self.width() || y >= self.height() {
+            return None;
+        }
+
+        let (mut x, mut y) = if self.transposed { (y, x) } else { (x, y) };
+
+        if self.flip_x {
+            x = self.inner.width() - 1 - x;
+        }
+
+        if self.flip_y {
+            y = self.inner.height() - 1 - y;
+        }
+
+        self.inner.get(PositioningType::Relative, x, y)
+    }
+
+    /// Creates an iterator that reads through the view lexicographically
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let view = self.clone();
+        let width = self.width();
+
+        (0 .. self.height())
+            .flat_map(move |y| (0 .. width).map(move |x| (x, y)))
+            .filter_map(move |(x, y)| view.get(x, y))
+    }
+
+    /// Creates a view reflected across the main diagonal of this one,
+    /// so the cell at (x, y) is the cell at (y, x) in this view and the width and height are swapped
+    #[inline]
+    pub fn transpose_view(&self) -> OrientedView<'a, T> {
+        OrientedView {
+            transposed: !self.transposed,
+            ..self.clone()
+        }
+    }
+
+    /// Creates a view of this one mirrored left to right,
+    /// so the cell at (x, y) is the cell at (width - 1 - x, y) in this view
+    #[inline]
+    pub fn flip_horizontal(&self) -> OrientedView<'a, T> {
+        let mut view = self.clone();
+
+        // The X axis of the view reads along the Y axis of the SubSpace when transposed
+        if self.transposed {
+            view.flip_y = !view.flip_y;
+        } else {
+            view.flip_x = !view.flip_x;
+        }
+
+        view
+    }
+
+    /// Creates a view of this one mirrored top to bottom,
+    /// so the cell at (x, y) is the cell at (x, height - 1 - y) in this view
+    #[inline]
+    pub fn flip_vertical(&self) -> OrientedView<'a, T> {
+        let mut view = self.clone();
+
+        if self.transposed {
+            view.flip_x = !view.flip_x;
+        } else {
+            view.flip_y = !view.flip_y;
+        }
+
+        view
+    }
+
+    /// Creates a view reflected across the main diagonal of this one, which is the same as `transpose_view`
+    #[inline]
+    pub fn flip_main_diagonal(&self) -> OrientedView<'a, T> {
+        self.transpose_view()
+    }
+
+    /// Creates a view reflected across the anti-diagonal of this one,
+    /// so the cell at (x, y) is the cell at (height - 1 - y, width - 1 - x), measured with
+    /// the dimensions of the result, and the width and height are swapped
+    #[inline]
+    pub fn flip_anti_diagonal(&self) -> OrientedView<'a, T> {
+        self.transpose_view().flip_horizontal().flip_vertical()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Space;
+
+    #[test]
+    fn wrapped_view_test() {
+        let space = Space::new_mapped(|x, y| (x, y), 4, 3);
+        let wrapped = space.as_subspace().wrapped();
+
+        assert_eq!(wrapped.get_signed(-1, -1), Some(&(3, 2)));
+        assert_eq!(wrapped.get_signed(-5, 7), Some(&(3, 1)));
+        assert_eq!(wrapped.get(4, 3), Some(&(0, 0)));
+        assert_eq!(wrapped.get(9, 1), Some(&(1, 1)));
+
+        let empty = Space::new_flat(0u8, 0, 3);
+
+        assert_eq!(empty.as_subspace().wrapped().get(0, 0), None);
+    }
+
+    #[test]
+    fn wrapped_windows_test() {
+        let space = Space::new_mapped(|x, y| y * 4 + x, 4, 3);
+
+        let windows = space.as_subspace().wrapped_windows(3, 3).collect::<Vec<_>>();
+
+        assert_eq!(windows.len(), 12);
+        assert!(windows.iter().all(|window| window.len() == 9));
+
+        // The top left corner pulls in the bottom row and the right column
+        assert_eq!(windows[0], vec![&11, &8, &9, &3, &0, &1, &7, &4, &5]);
+    }
+
+    #[test]
+    fn subsample_test() {
+        let space = Space::new_mapped(|x, y| y * 4 + x, 4, 4);
+
+        let thumbnail = space.as_subspace().subsample(2, 2);
+
+        assert_eq!((thumbnail.width(), thumbnail.height()), (2, 2));
+        assert_eq!(thumbnail.iter().copied().collect::<Vec<_>>(), vec![0, 2, 8, 10]);
+        assert_eq!(thumbnail.get(2, 0), None);
+
+        let uneven = space.as_subspace_at(1, 0, 3, 4).unwrap().subsample(2, 3);
+
+        assert_eq!((uneven.width(), uneven.height()), (2, 2));
+        assert_eq!(uneven.iter().copied().collect::<Vec<_>>(), vec![1, 3, 13, 15]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn subsample_zero_step_test() {
+        let space = Space::new_flat(0u8, 4, 4);
+
+        space.as_subspace().subsample(0, 1);
+    }
+
+    #[test]
+    fn dihedral_views_test() {
+        // 0 1
+        // 2 3
+        // 4 5
+        let space = Space::new_mapped(|x, y| y * 2 + x, 2, 3);
+        let subspace = space.as_subspace();
+
+        let read = |view: OrientedView<'_, usize>| (view.width(), view.height(), view.iter().copied().collect::<Vec<_>>());
+        let owned = |space: Space<usize>| (space.width(), space.height(), space.iter().copied().collect::<Vec<_>>());
+
+        assert_eq!(read(subspace.oriented()), owned(space.clone()));
+        assert_eq!(read(subspace.flip_horizontal()), (2, 3, vec![1, 0, 3, 2, 5, 4]));
+        assert_eq!(read(subspace.flip_vertical()), (2, 3, vec![4, 5, 2, 3, 0, 1]));
+        assert_eq!(read(subspace.flip_horizontal().flip_vertical()), owned(space.rotate(Rotation::Cw180)));
+        assert_eq!(read(subspace.flip_main_diagonal()), owned(space.transpose()));
+        assert_eq!(read(subspace.flip_anti_diagonal()), owned(space.mirror_anti_diagonal()));
+        assert_eq!(read(subspace.transpose_view().flip_horizontal()), owned(space.rotate(Rotation::Cw90)));
+        assert_eq!(read(subspace.transpose_view().flip_vertical()), owned(space.rotate(Rotation::Cw270)));
+
+        assert_eq!(read(subspace.flip_anti_diagonal().flip_anti_diagonal()), owned(space.clone()));
+        assert_eq!(subspace.transpose_view().get(2, 0), Some(&4));
+        assert_eq!(subspace.transpose_view().get(0, 2), None);
+    }
+
+    #[test]
+    fn column_optimized_test() {
+        let mut space = Space::new_mapped(|x, y| y * 4 + x, 4, 3);
+
+        let columns = space.column_optimized();
+
+        assert_eq!((columns.width(), columns.height()), (4, 3));
+        assert_eq!(columns.column(2), Some(&[2, 6, 10][..]));
+        assert_eq!(columns.column(4), None);
+
+        let strided = (0 .. 3).map(|y| *space.get(2, y).unwrap()).collect::<Vec<_>>();
+
+        assert_eq!(columns.column(2).unwrap(), strided.as_slice());
+
+        // The snapshot is unaffected by later changes
+        space.set(2, 0, 99);
+
+        assert_eq!(columns.column(2).unwrap()[0], 2);
+    }
+
+    #[test]
+    fn oriented_view_test() {
+        let space = Space::new_mapped(|x, y| y * 5 + x, 5, 3);
+
+        let view = space.oriented_view(Orientation::Rotate90);
+        let rotated = space.rotate(Rotation::Cw90);
+
+        assert_eq!((view.width(), view.height()), (3, 5));
+        assert!(view.iter().eq(rotated.iter()));
+
+        for value in 1 ..= 8 {
+            let orientation = Orientation::from_exif(value).unwrap();
+            let view = space.oriented_view(orientation);
+            let owned = space.oriented(orientation);
+
+            assert_eq!((view.width(), view.height()), (owned.width(), owned.height()));
+            assert!(view.iter().eq(owned.iter()));
+        }
+    }
+}